@@ -0,0 +1,622 @@
+use soroban_sdk::{contracttype, Address, Bytes, BytesN, Env, Vec};
+use crate::{error::StellarSaveError, storage::StorageKeyBuilder, AssignmentMode, MemberProfile};
+
+/// Record of a randomized payout order, kept so any member can verify the draw.
+///
+/// Re-running `shuffle` with `seed` over positions `0..order.len()` (`Random`)
+/// or `0..reveal_count` for the revealing members in join order
+/// (`CommitReveal`) reproduces the assignment: `order` lists the members by
+/// payout position.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutDraw {
+    /// Seed the shuffle was run with
+    pub seed: BytesN<32>,
+
+    /// Members ordered by payout position (index 0 is paid first)
+    pub order: Vec<Address>,
+
+    /// Number of revealed member seeds mixed into `seed`
+    pub reveal_count: u32,
+
+    /// Timestamp of the draw
+    pub drawn_at: u64,
+}
+
+/// A member's offer to exchange payout positions with another member.
+///
+/// The positions are those held when the swap was proposed; it can only be
+/// accepted while both members still hold them.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SwapProposal {
+    /// Member who proposed the swap
+    pub from: Address,
+
+    /// Payout position of `from` when the swap was proposed
+    pub from_position: u32,
+
+    /// Payout position of the member the swap was proposed to
+    pub to_position: u32,
+
+    /// Timestamp the swap was proposed
+    pub proposed_at: u64,
+}
+
+/// Returns the group's assignment mode: the one chosen at creation, then
+/// the one its payout positions were assigned with.
+pub fn assignment_mode(env: &Env, group_id: u64) -> Option<AssignmentMode> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_assignment_mode(group_id))
+}
+
+/// Sets a member's payout position.
+///
+/// `MemberProfile::payout_position` is the source of truth; the payout
+/// eligibility entry mirrors it, and the position → member index lets
+/// payouts find a cycle's recipient without scanning the members. All
+/// position changes go through here so the three stay consistent. A swap
+/// proposed to the member was made against the old position and is dropped.
+pub fn set_position(env: &Env, profile: &mut MemberProfile, position: u32) {
+    let group_id = profile.group_id;
+    profile.payout_position = position;
+    env.storage().persistent().set(
+        &StorageKeyBuilder::member_profile(group_id, profile.address.clone()),
+        profile,
+    );
+    env.storage().persistent().set(
+        &StorageKeyBuilder::member_payout_eligibility(group_id, profile.address.clone()),
+        &position,
+    );
+    env.storage().persistent().set(
+        &StorageKeyBuilder::group_position_member(group_id, position),
+        &profile.address,
+    );
+    env.storage()
+        .persistent()
+        .remove(&StorageKeyBuilder::member_swap_proposal(group_id, profile.address.clone()));
+}
+
+/// Returns the member holding `position` in the group's payout order.
+pub fn position_holder(env: &Env, group_id: u64, position: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_position_member(group_id, position))
+}
+
+/// Checks that `positions` is a permutation of `0..len`.
+///
+/// # Errors
+/// * `InvalidState` - Wrong length, a position out of range, or a duplicate
+pub fn validate_permutation(env: &Env, positions: &Vec<u32>, len: u32) -> Result<(), StellarSaveError> {
+    if positions.len() != len {
+        return Err(StellarSaveError::InvalidState);
+    }
+    let mut seen = Vec::new(env);
+    for _ in 0..len {
+        seen.push_back(false);
+    }
+    for position in positions.iter() {
+        if position >= len || seen.get(position).unwrap() {
+            return Err(StellarSaveError::InvalidState);
+        }
+        seen.set(position, true);
+    }
+    Ok(())
+}
+
+/// Lists `members` by payout position, given each member's position in the
+/// order of `members`.
+pub fn payout_order(members: &Vec<Address>, positions: &Vec<u32>) -> Vec<Address> {
+    let mut order = members.clone();
+    for (idx, member) in members.iter().enumerate() {
+        order.set(positions.get(idx as u32).unwrap(), member);
+    }
+    order
+}
+
+/// Exchanges the payout positions of two members (see `set_position`).
+///
+/// # Returns
+/// The new positions of `a` and `b`.
+///
+/// # Errors
+/// * `NotMember` - Either address is not a member of the group
+pub fn swap_positions(
+    env: &Env,
+    group_id: u64,
+    a: &Address,
+    b: &Address,
+) -> Result<(u32, u32), StellarSaveError> {
+    let mut profile_a: MemberProfile = env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_profile(group_id, a.clone()))
+        .ok_or(StellarSaveError::NotMember)?;
+    let mut profile_b: MemberProfile = env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_profile(group_id, b.clone()))
+        .ok_or(StellarSaveError::NotMember)?;
+
+    let position_a = profile_a.payout_position;
+    let position_b = profile_b.payout_position;
+    set_position(env, &mut profile_a, position_b);
+    set_position(env, &mut profile_b, position_a);
+    Ok((position_b, position_a))
+}
+
+/// Shuffles `positions` in place with a Fisher–Yates shuffle driven by the
+/// Soroban PRNG reseeded with `seed`, so the same seed always yields the
+/// same order.
+pub fn shuffle(env: &Env, positions: &mut Vec<u32>, seed: &BytesN<32>) {
+    env.prng().seed(Bytes::from(seed.clone()));
+
+    let len = positions.len();
+    for i in (1..len).rev() {
+        let j = env.prng().gen_range::<u64>(0..=i as u64) as u32;
+        let a = positions.get(i).unwrap();
+        let b = positions.get(j).unwrap();
+        positions.set(i, b);
+        positions.set(j, a);
+    }
+}
+
+/// XORs two 32-byte values.
+pub fn xor(env: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let a = a.to_array();
+    let b = b.to_array();
+    let mut out = [0u8; 32];
+    for i in 0..32 {
+        out[i] = a[i] ^ b[i];
+    }
+    BytesN::from_array(env, &out)
+}
+
+/// Returns the commitment for a member seed: `sha256(seed)`.
+pub fn commitment(env: &Env, seed: &BytesN<32>) -> BytesN<32> {
+    env.crypto().sha256(&Bytes::from(seed.clone())).into()
+}
+
+/// Returns true once the group's commit phase is over: every member has
+/// committed, or the commit deadline set at creation has passed.
+pub fn commit_phase_over(env: &Env, group_id: u64, members: &Vec<Address>) -> bool {
+    let deadline: Option<u64> = env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_commit_deadline(group_id));
+    if let Some(deadline) = deadline {
+        if env.ledger().timestamp() >= deadline {
+            return true;
+        }
+    }
+    members.iter().all(|member| {
+        env.storage()
+            .persistent()
+            .has(&StorageKeyBuilder::member_seed_commit(group_id, member))
+    })
+}
+
+/// Returns true once the group's reveal phase is over: every member who
+/// committed has revealed, or the reveal deadline set at creation has passed.
+pub fn reveal_phase_over(env: &Env, group_id: u64, members: &Vec<Address>) -> bool {
+    if reveal_deadline_passed(env, group_id) {
+        return true;
+    }
+    members.iter().all(|member| {
+        !env.storage()
+            .persistent()
+            .has(&StorageKeyBuilder::member_seed_commit(group_id, member.clone()))
+            || revealed_seed(env, group_id, &member).is_some()
+    })
+}
+
+/// Returns true once a seeded draw may run: both the commit and the reveal
+/// phase are over, so every member had the chance to commit and the
+/// committers' reveals are final.
+pub fn draw_ready(env: &Env, group_id: u64, members: &Vec<Address>) -> bool {
+    commit_phase_over(env, group_id, members) && reveal_phase_over(env, group_id, members)
+}
+
+/// Returns true if any member has committed to a seed.
+pub fn any_committed(env: &Env, group_id: u64, members: &Vec<Address>) -> bool {
+    members.iter().any(|member| {
+        env.storage()
+            .persistent()
+            .has(&StorageKeyBuilder::member_seed_commit(group_id, member))
+    })
+}
+
+/// Returns true if any member has revealed their seed.
+pub fn any_revealed(env: &Env, group_id: u64, members: &Vec<Address>) -> bool {
+    members.iter().any(|member| revealed_seed(env, group_id, &member).is_some())
+}
+
+/// Returns true if the group has a reveal deadline and it has passed.
+fn reveal_deadline_passed(env: &Env, group_id: u64) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, u64>(&StorageKeyBuilder::group_reveal_deadline(group_id))
+        .is_some_and(|deadline| env.ledger().timestamp() >= deadline)
+}
+
+/// Records a member's commitment to a seed for the group's payout draw.
+///
+/// Commitments close at the commit deadline and as soon as any seed has been
+/// revealed, so no one can pick a seed knowing another member's.
+///
+/// # Errors
+/// * `InvalidState` - The group does not use a seeded draw, the commit
+///   deadline has passed, or a seed was already revealed
+/// * `AlreadyContributed` - The member already committed
+pub fn commit_seed(
+    env: &Env,
+    group_id: u64,
+    members: &Vec<Address>,
+    member: &Address,
+    commitment: &BytesN<32>,
+) -> Result<(), StellarSaveError> {
+    match assignment_mode(env, group_id) {
+        None | Some(AssignmentMode::Random) | Some(AssignmentMode::CommitReveal) => {}
+        Some(_) => return Err(StellarSaveError::InvalidState),
+    }
+
+    let deadline: Option<u64> = env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_commit_deadline(group_id));
+    if deadline.is_some_and(|deadline| env.ledger().timestamp() >= deadline)
+        || any_revealed(env, group_id, members)
+    {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    let key = StorageKeyBuilder::member_seed_commit(group_id, member.clone());
+    if env.storage().persistent().has(&key) {
+        return Err(StellarSaveError::AlreadyContributed);
+    }
+    env.storage().persistent().set(&key, commitment);
+    Ok(())
+}
+
+/// Reveals a member's committed seed after checking it against the commitment.
+///
+/// Reveals are only accepted once the commit phase is over (see
+/// `commit_phase_over`) and until the reveal deadline.
+///
+/// # Errors
+/// * `InvalidState` - The commit phase is still open, the reveal deadline
+///   has passed, no commitment, already revealed, or the seed does not
+///   match the commitment
+pub fn reveal_seed(
+    env: &Env,
+    group_id: u64,
+    members: &Vec<Address>,
+    member: &Address,
+    seed: &BytesN<32>,
+) -> Result<(), StellarSaveError> {
+    if !commit_phase_over(env, group_id, members) || reveal_deadline_passed(env, group_id) {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    let committed: BytesN<32> = env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_seed_commit(group_id, member.clone()))
+        .ok_or(StellarSaveError::InvalidState)?;
+
+    let reveal_key = StorageKeyBuilder::member_seed_reveal(group_id, member.clone());
+    if env.storage().persistent().has(&reveal_key) || commitment(env, seed) != committed {
+        return Err(StellarSaveError::InvalidState);
+    }
+    env.storage().persistent().set(&reveal_key, seed);
+    Ok(())
+}
+
+/// Returns the member's revealed seed, if any.
+pub fn revealed_seed(env: &Env, group_id: u64, member: &Address) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_seed_reveal(group_id, member.clone()))
+}
+
+/// Draws a random payout order for `members` and persists the draw.
+///
+/// The seed comes from the Soroban PRNG, XORed with every seed members have
+/// revealed, so neither the ledger nor any single party controls the result
+/// as long as one revealed seed was kept secret until its reveal.
+///
+/// # Returns
+/// The payout position of each member, in the order of `members`.
+pub fn draw_random(env: &Env, group_id: u64, members: &Vec<Address>) -> Vec<u32> {
+    let mut seed: BytesN<32> = env.prng().gen();
+    let mut reveal_count = 0;
+    for member in members.iter() {
+        if let Some(revealed) = revealed_seed(env, group_id, &member) {
+            seed = xor(env, &seed, &revealed);
+            reveal_count += 1;
+        }
+    }
+
+    let mut positions = Vec::new(env);
+    for i in 0..members.len() {
+        positions.push_back(i);
+    }
+    shuffle(env, &mut positions, &seed);
+
+    save_draw(env, group_id, members, &positions, seed, reveal_count);
+    positions
+}
+
+/// Draws a payout order seeded only by the XOR of the members' revealed seeds.
+///
+/// Members who revealed are shuffled into the first positions; members who
+/// did not reveal follow in join order. Withholding a reveal therefore cannot
+/// improve a member's position, and no party outside the group influences
+/// the seed.
+///
+/// # Returns
+/// The payout position of each member, in the order of `members`.
+pub fn draw_commit_reveal(env: &Env, group_id: u64, members: &Vec<Address>) -> Vec<u32> {
+    let mut seed = BytesN::from_array(env, &[0; 32]);
+    let mut revealed = Vec::new(env);
+    for member in members.iter() {
+        let reveal = revealed_seed(env, group_id, &member);
+        if let Some(reveal) = &reveal {
+            seed = xor(env, &seed, reveal);
+        }
+        revealed.push_back(reveal.is_some());
+    }
+
+    let reveal_count = revealed.iter().filter(|r| *r).count() as u32;
+    let mut shuffled = Vec::new(env);
+    for i in 0..reveal_count {
+        shuffled.push_back(i);
+    }
+    shuffle(env, &mut shuffled, &seed);
+
+    let mut positions = Vec::new(env);
+    let mut next_revealer = 0;
+    let mut next_silent = reveal_count;
+    for did_reveal in revealed.iter() {
+        if did_reveal {
+            positions.push_back(shuffled.get(next_revealer).unwrap());
+            next_revealer += 1;
+        } else {
+            positions.push_back(next_silent);
+            next_silent += 1;
+        }
+    }
+
+    save_draw(env, group_id, members, &positions, seed, reveal_count);
+    positions
+}
+
+/// Persists the seed and resulting order of a payout draw.
+pub fn save_draw(
+    env: &Env,
+    group_id: u64,
+    members: &Vec<Address>,
+    positions: &Vec<u32>,
+    seed: BytesN<32>,
+    reveal_count: u32,
+) {
+    let draw = PayoutDraw {
+        seed,
+        order: payout_order(members, positions),
+        reveal_count,
+        drawn_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&StorageKeyBuilder::group_payout_draw(group_id), &draw);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StellarSaveContract;
+    use soroban_sdk::testutils::{Address as _, Ledger as _};
+
+    fn positions(env: &Env, n: u32) -> Vec<u32> {
+        let mut v = Vec::new(env);
+        for i in 0..n {
+            v.push_back(i);
+        }
+        v
+    }
+
+    #[test]
+    fn test_shuffle_is_deterministic_permutation() {
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        env.as_contract(&contract_id, || {
+            let seed = BytesN::from_array(&env, &[7; 32]);
+            let mut first = positions(&env, 10);
+            let mut second = positions(&env, 10);
+            shuffle(&env, &mut first, &seed);
+            shuffle(&env, &mut second, &seed);
+
+            assert_eq!(first, second);
+            for i in 0..10 {
+                assert!(first.contains(i));
+            }
+        });
+    }
+
+    #[test]
+    fn test_shuffle_moves_first_element() {
+        // The old shuffle always swapped with index 0; over several seeds the
+        // Fisher-Yates shuffle must not leave position 0 fixed every time.
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        env.as_contract(&contract_id, || {
+            let mut moved = false;
+            for s in 0..8u8 {
+                let mut v = positions(&env, 5);
+                shuffle(&env, &mut v, &BytesN::from_array(&env, &[s; 32]));
+                moved |= v.get(0).unwrap() != 0;
+            }
+            assert!(moved);
+        });
+    }
+
+    #[test]
+    fn test_commit_reveal() {
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        let member = Address::generate(&env);
+        let other = Address::generate(&env);
+        let members = Vec::from_array(&env, [member.clone(), other.clone()]);
+        env.as_contract(&contract_id, || {
+            let seed = BytesN::from_array(&env, &[3; 32]);
+            let wrong = BytesN::from_array(&env, &[4; 32]);
+            commit_seed(&env, 1, &members, &member, &commitment(&env, &seed)).unwrap();
+
+            // Reveals wait until every member has committed
+            assert_eq!(reveal_seed(&env, 1, &members, &member, &seed), Err(StellarSaveError::InvalidState));
+            commit_seed(&env, 1, &members, &other, &commitment(&env, &wrong)).unwrap();
+
+            assert_eq!(reveal_seed(&env, 1, &members, &member, &wrong), Err(StellarSaveError::InvalidState));
+            reveal_seed(&env, 1, &members, &member, &seed).unwrap();
+            assert_eq!(revealed_seed(&env, 1, &member), Some(seed));
+        });
+    }
+
+    #[test]
+    fn test_commit_deadline_closes_commits_and_opens_reveals() {
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        let member = Address::generate(&env);
+        let members = Vec::from_array(&env, [member.clone(), Address::generate(&env)]);
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(&StorageKeyBuilder::group_commit_deadline(1), &100u64);
+            let seed = BytesN::from_array(&env, &[3; 32]);
+            commit_seed(&env, 1, &members, &member, &commitment(&env, &seed)).unwrap();
+            assert!(!commit_phase_over(&env, 1, &members));
+
+            env.ledger().with_mut(|li| li.timestamp = 100);
+            assert!(commit_phase_over(&env, 1, &members));
+            reveal_seed(&env, 1, &members, &member, &seed).unwrap();
+        });
+    }
+
+    #[test]
+    fn test_reveal_deadline_closes_reveals_and_readies_draw() {
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        let members = Vec::from_array(&env, [a.clone(), b.clone()]);
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(&StorageKeyBuilder::group_commit_deadline(1), &100u64);
+            env.storage().persistent().set(&StorageKeyBuilder::group_reveal_deadline(1), &200u64);
+            let seed = BytesN::from_array(&env, &[3; 32]);
+            commit_seed(&env, 1, &members, &a, &commitment(&env, &seed)).unwrap();
+            commit_seed(&env, 1, &members, &b, &commitment(&env, &seed)).unwrap();
+
+            // b's reveal is outstanding until the deadline
+            reveal_seed(&env, 1, &members, &a, &seed).unwrap();
+            assert!(!draw_ready(&env, 1, &members));
+
+            env.ledger().with_mut(|li| li.timestamp = 200);
+            assert!(draw_ready(&env, 1, &members));
+            assert_eq!(
+                reveal_seed(&env, 1, &members, &b, &seed),
+                Err(StellarSaveError::InvalidState)
+            );
+        });
+    }
+
+    #[test]
+    fn test_commit_reveal_draw_places_non_revealers_last() {
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        let mut members = Vec::new(&env);
+        for _ in 0..5 {
+            members.push_back(Address::generate(&env));
+        }
+        env.as_contract(&contract_id, || {
+            // Everyone commits; members 1 and 3 reveal, 0, 2 and 4 stay silent
+            for (idx, member) in members.iter().enumerate() {
+                let seed = BytesN::from_array(&env, &[idx as u8 + 4; 32]);
+                commit_seed(&env, 1, &members, &member, &commitment(&env, &seed)).unwrap();
+            }
+            for (idx, byte) in [(1u32, 5u8), (3, 7)] {
+                let seed = BytesN::from_array(&env, &[byte; 32]);
+                reveal_seed(&env, 1, &members, &members.get(idx).unwrap(), &seed).unwrap();
+            }
+
+            let positions = draw_commit_reveal(&env, 1, &members);
+            assert!(positions.get(1).unwrap() < 2);
+            assert!(positions.get(3).unwrap() < 2);
+            assert_eq!(positions.get(0), Some(2));
+            assert_eq!(positions.get(2), Some(3));
+            assert_eq!(positions.get(4), Some(4));
+
+            let draw: PayoutDraw = env.storage()
+                .persistent()
+                .get(&StorageKeyBuilder::group_payout_draw(1))
+                .unwrap();
+            assert_eq!(draw.reveal_count, 2);
+            assert_eq!(draw.seed, BytesN::from_array(&env, &[5 ^ 7; 32]));
+        });
+    }
+
+    #[test]
+    fn test_swap_positions_updates_profile_and_eligibility() {
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        let a = Address::generate(&env);
+        let b = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            for (member, position) in [(&a, 0u32), (&b, 3)] {
+                let profile = MemberProfile {
+                    address: member.clone(),
+                    group_id: 1,
+                    payout_position: position,
+                    joined_at: 0,
+                };
+                env.storage().persistent().set(&StorageKeyBuilder::member_profile(1, member.clone()), &profile);
+            }
+
+            assert_eq!(swap_positions(&env, 1, &a, &b), Ok((3, 0)));
+
+            let profile: MemberProfile = env.storage()
+                .persistent()
+                .get(&StorageKeyBuilder::member_profile(1, a.clone()))
+                .unwrap();
+            assert_eq!(profile.payout_position, 3);
+            let eligibility: u32 = env.storage()
+                .persistent()
+                .get(&StorageKeyBuilder::member_payout_eligibility(1, b.clone()))
+                .unwrap();
+            assert_eq!(eligibility, 0);
+            assert_eq!(position_holder(&env, 1, 0), Some(b.clone()));
+            assert_eq!(position_holder(&env, 1, 3), Some(a.clone()));
+            assert_eq!(
+                swap_positions(&env, 1, &a, &Address::generate(&env)),
+                Err(StellarSaveError::NotMember)
+            );
+        });
+    }
+
+    #[test]
+    fn test_validate_permutation() {
+        let env = Env::default();
+        let valid = Vec::from_array(&env, [2u32, 0, 1]);
+        assert_eq!(validate_permutation(&env, &valid, 3), Ok(()));
+
+        for invalid in [
+            Vec::from_array(&env, [0u32, 1]),
+            Vec::from_array(&env, [0u32, 1, 1]),
+            Vec::from_array(&env, [0u32, 1, 3]),
+        ] {
+            assert_eq!(validate_permutation(&env, &invalid, 3), Err(StellarSaveError::InvalidState));
+        }
+    }
+
+    #[test]
+    fn test_xor() {
+        let env = Env::default();
+        let a = BytesN::from_array(&env, &[0b1010; 32]);
+        let b = BytesN::from_array(&env, &[0b0110; 32]);
+        assert_eq!(xor(&env, &a, &b), BytesN::from_array(&env, &[0b1100; 32]));
+    }
+}
@@ -0,0 +1,183 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::{
+    assignment,
+    error::StellarSaveError,
+    events::EventEmitter,
+    group::Group,
+    storage::StorageKeyBuilder,
+    AssignmentMode, MemberProfile,
+};
+
+/// Returns true if the group's payout positions were assigned in `Auction` mode.
+pub fn is_auction(env: &Env, group_id: u64) -> bool {
+    assignment::assignment_mode(env, group_id) == Some(AssignmentMode::Auction)
+}
+
+/// Records a member's bid for the payout of the group's current cycle.
+///
+/// Only members who have not been paid yet can bid, and only while at least
+/// two of them remain. A new bid replaces the member's previous one.
+///
+/// # Errors
+/// * `InvalidState` - Not an auction group, not active, not the current
+///   cycle, the member was already paid, or only one unpaid member remains
+/// * `PayoutAlreadyProcessed` - The cycle was already paid out
+/// * `NotMember` - The bidder is not a member
+/// * `InvalidAmount` - The discount is negative or not below the full pool
+pub fn place_bid(
+    env: &Env,
+    group: &Group,
+    cycle: u32,
+    member: &Address,
+    discount: i128,
+) -> Result<(), StellarSaveError> {
+    let group_id = group.id;
+    if !is_auction(env, group_id)
+        || !group.status.can_process_payouts()
+        || cycle != group.current_cycle
+        || cycle + 1 >= group.member_count
+    {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    if env.storage()
+        .persistent()
+        .get::<_, bool>(&StorageKeyBuilder::payout_status(group_id, cycle))
+        .unwrap_or(false)
+    {
+        return Err(StellarSaveError::PayoutAlreadyProcessed);
+    }
+
+    let profile: MemberProfile = env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_profile(group_id, member.clone()))
+        .ok_or(StellarSaveError::NotMember)?;
+    if profile.payout_position < cycle {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    let full_pool = group.contribution_amount
+        .checked_mul(group.member_count as i128)
+        .ok_or(StellarSaveError::Overflow)?;
+    if discount < 0 || discount >= full_pool {
+        return Err(StellarSaveError::InvalidAmount);
+    }
+
+    env.storage()
+        .persistent()
+        .set(&StorageKeyBuilder::member_bid(group_id, cycle, member.clone()), &discount);
+    EventEmitter::emit_bid_placed(env, group_id, member.clone(), cycle, discount, env.ledger().timestamp());
+    Ok(())
+}
+
+/// Settles the auction for `cycle` and returns the winner and the discount.
+///
+/// The highest bid among unpaid members wins, ties going to the member
+/// scheduled earliest. The winner swaps payout positions with `scheduled`,
+/// the member who held this cycle's position. The discount (capped at `pool`)
+/// is split evenly among the other members as credit towards their next
+/// contributions; any remainder goes to the group reserve. Without bids,
+/// `scheduled` wins with no discount.
+pub fn settle(
+    env: &Env,
+    group: &Group,
+    cycle: u32,
+    pool: i128,
+    scheduled: Address,
+) -> Result<(Address, i128), StellarSaveError> {
+    let group_id = group.id;
+    let members: Vec<Address> = env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_members(group_id))
+        .ok_or(StellarSaveError::GroupNotFound)?;
+
+    // Highest bid: (member, profile, discount)
+    let mut best: Option<(Address, MemberProfile, i128)> = None;
+    for member in members.iter() {
+        let bid: Option<i128> = env.storage()
+            .persistent()
+            .get(&StorageKeyBuilder::member_bid(group_id, cycle, member.clone()));
+        let Some(discount) = bid else { continue };
+        let profile: MemberProfile = env.storage()
+            .persistent()
+            .get(&StorageKeyBuilder::member_profile(group_id, member.clone()))
+            .ok_or(StellarSaveError::NotMember)?;
+        if profile.payout_position < cycle {
+            continue;
+        }
+        let better = match &best {
+            None => true,
+            Some((_, best_profile, best_discount)) => {
+                discount > *best_discount
+                    || (discount == *best_discount
+                        && profile.payout_position < best_profile.payout_position)
+            }
+        };
+        if better {
+            best = Some((member, profile, discount));
+        }
+    }
+
+    let Some((winner, _, bid)) = best else {
+        return Ok((scheduled, 0));
+    };
+
+    if winner != scheduled {
+        assignment::swap_positions(env, group_id, &winner, &scheduled)?;
+    }
+
+    let discount = if bid > pool { pool } else { bid };
+    let others = members.len().saturating_sub(1) as i128;
+    let dividend = if others > 0 { discount / others } else { 0 };
+    if dividend > 0 {
+        for member in members.iter() {
+            if member == winner {
+                continue;
+            }
+            let credit_key = StorageKeyBuilder::member_contribution_credit(group_id, member);
+            let credit: i128 = env.storage().persistent().get(&credit_key).unwrap_or(0);
+            let credit = credit.checked_add(dividend).ok_or(StellarSaveError::Overflow)?;
+            env.storage().persistent().set(&credit_key, &credit);
+        }
+    }
+
+    let remainder = discount - dividend * others;
+    if remainder > 0 {
+        let reserve_key = StorageKeyBuilder::group_reserve(group_id);
+        let reserve: i128 = env.storage().persistent().get(&reserve_key).unwrap_or(0);
+        let reserve = reserve.checked_add(remainder).ok_or(StellarSaveError::Overflow)?;
+        env.storage().persistent().set(&reserve_key, &reserve);
+    }
+
+    EventEmitter::emit_auction_settled(
+        env,
+        group_id,
+        winner.clone(),
+        cycle,
+        discount,
+        dividend,
+        env.ledger().timestamp(),
+    );
+    Ok((winner, discount))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StellarSaveContract;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_is_auction_reads_assignment_mode() {
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        env.as_contract(&contract_id, || {
+            let key = StorageKeyBuilder::group_assignment_mode(1);
+            assert!(!is_auction(&env, 1));
+            env.storage().persistent().set(&key, &AssignmentMode::Sequential);
+            assert!(!is_auction(&env, 1));
+            env.storage().persistent().set(&key, &AssignmentMode::Auction);
+            assert!(is_auction(&env, 1));
+        });
+    }
+}
@@ -28,6 +28,18 @@ pub struct ContributionRecord {
     /// Timestamp when the contribution was made (Unix timestamp in seconds).
     /// Used for tracking contribution timing and enforcing deadlines.
     pub timestamp: u64,
+
+    /// Whether the contribution was made after the cycle deadline
+    /// (within the group's grace period).
+    pub is_late: bool,
+
+    /// Late penalty charged on top of `amount` and credited to the group reserve.
+    /// Zero for on-time contributions.
+    pub penalty: i128,
+
+    /// Part of `amount` covered by the member's auction dividend credit
+    /// instead of a transfer. Those funds were already held by the contract.
+    pub credit_used: i128,
 }
 
 impl ContributionRecord {
@@ -59,9 +71,48 @@ impl ContributionRecord {
             cycle_number,
             amount,
             timestamp,
+            is_late: false,
+            penalty: 0,
+            credit_used: 0,
         }
     }
 
+    /// Creates a ContributionRecord for a contribution made during the grace period.
+    /// 
+    /// # Arguments
+    /// * `member_address` - Address of the contributing member
+    /// * `group_id` - ID of the group receiving the contribution
+    /// * `cycle_number` - Current cycle number
+    /// * `amount` - Contribution amount in stroops
+    /// * `timestamp` - Contribution timestamp
+    /// * `penalty` - Late penalty charged in addition to `amount`
+    /// 
+    /// # Panics
+    /// Panics if validation constraints are violated:
+    /// - amount must be > 0
+    /// - penalty must be >= 0
+    pub fn new_late(
+        member_address: Address,
+        group_id: u64,
+        cycle_number: u32,
+        amount: i128,
+        timestamp: u64,
+        penalty: i128,
+    ) -> Self {
+        assert!(penalty >= 0, "penalty must not be negative");
+
+        let mut record = Self::new(member_address, group_id, cycle_number, amount, timestamp);
+        record.is_late = true;
+        record.penalty = penalty;
+        record
+    }
+
+    /// Returns the amount the member transferred for this contribution:
+    /// the contribution less any credit applied, plus the penalty.
+    pub fn total_paid(&self) -> i128 {
+        self.amount - self.credit_used + self.penalty
+    }
+
     /// Validates that the contribution record is sound.
     /// Returns true if all constraints are met.
     pub fn validate(&self) -> bool {
@@ -111,6 +162,26 @@ mod tests {
         assert_eq!(contribution.timestamp, 1234567890);
     }
 
+    #[test]
+    fn test_late_contribution_record() {
+        let env = Env::default();
+        let member = Address::generate(&env);
+        
+        let on_time = ContributionRecord::new(member.clone(), 1, 0, 10_000_000, 1234567890);
+        assert!(!on_time.is_late);
+        assert_eq!(on_time.penalty, 0);
+        assert_eq!(on_time.total_paid(), 10_000_000);
+        
+        let late = ContributionRecord::new_late(member.clone(), 1, 0, 10_000_000, 1234567890, 500_000);
+        assert!(late.is_late);
+        assert_eq!(late.penalty, 500_000);
+        assert_eq!(late.total_paid(), 10_500_000);
+
+        let mut credited = ContributionRecord::new_late(member, 1, 0, 10_000_000, 1234567890, 500_000);
+        credited.credit_used = 3_000_000;
+        assert_eq!(credited.total_paid(), 7_500_000);
+    }
+
     #[test]
     #[should_panic(expected = "amount must be greater than 0")]
     fn test_invalid_amount() {
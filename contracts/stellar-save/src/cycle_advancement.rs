@@ -1,8 +1,8 @@
 use soroban_sdk::{Address, Env};
 use crate::{
     error::StellarSaveError,
-    events::EventEmitter,
     group::Group,
+    status::{self, GroupStatus},
     storage::StorageKeyBuilder,
 };
 
@@ -28,9 +28,9 @@ use crate::{
 ///
 /// # Side Effects
 /// * Updates group's current_cycle counter
-/// * Updates group's is_active flag if group is now complete
 /// * Persists updated group to storage
-/// * Emits GroupStatusChanged event if group transitions to Completed
+/// * Transitions the group to Completed (emitting GroupStatusChanged) once
+///   every member has had their payout cycle
 pub fn advance_group_to_next_cycle(
     env: &Env,
     group: &mut Group,
@@ -50,21 +50,9 @@ pub fn advance_group_to_next_cycle(
     let group_key = StorageKeyBuilder::group_data(group_id);
     env.storage().persistent().set(&group_key, group);
 
-    // Task 4: Emit event
-    // Emit GroupStatusChanged event when transitioning to Completed state
-    if group.is_complete() {
-        let old_status = (group.current_cycle - 1) as u32;
-        let new_status = 3u32; // Completed status code
-        let timestamp = env.ledger().timestamp();
-
-        EventEmitter::emit_group_status_changed(
-            env,
-            group_id,
-            old_status,
-            new_status,
-            caller.clone(),
-            timestamp,
-        );
+    // Task 4: Complete the group once every member has been paid
+    if group.rotation_finished() {
+        status::transition(env, group, GroupStatus::Completed, caller)?;
     }
 
     Ok(())
@@ -74,6 +62,7 @@ pub fn advance_group_to_next_cycle(
 /// This is useful for testing and for scenarios where storage is managed separately.
 ///
 /// # Arguments
+/// * `env` - The Soroban environment (used for the completion timestamp)
 /// * `group` - Mutable reference to the group being advanced
 ///
 /// # Returns
@@ -82,7 +71,7 @@ pub fn advance_group_to_next_cycle(
 ///
 /// # Errors
 /// * `InvalidState` - If the group is already complete
-pub fn advance_group_cycle_logic(group: &mut Group) -> Result<(), StellarSaveError> {
+pub fn advance_group_cycle_logic(env: &Env, group: &mut Group) -> Result<(), StellarSaveError> {
     // Verify cycle is complete
     if group.is_complete() {
         return Err(StellarSaveError::InvalidState);
@@ -91,6 +80,11 @@ pub fn advance_group_cycle_logic(group: &mut Group) -> Result<(), StellarSaveErr
     // Increment cycle counter
     group.advance_cycle();
 
+    // Complete the group once every member has been paid
+    if group.rotation_finished() {
+        group.set_status(GroupStatus::Completed, env.ledger().timestamp())?;
+    }
+
     Ok(())
 }
 
@@ -99,6 +93,12 @@ mod tests {
     use super::*;
     use soroban_sdk::{testutils::Address as _, Env};
 
+    /// Fills the group to capacity and activates it.
+    fn start(group: &mut Group) {
+        group.member_count = group.max_members;
+        group.set_status(GroupStatus::Active, 1234567890).unwrap();
+    }
+
     #[test]
     fn test_advance_group_cycle_logic_success() {
         let env = Env::default();
@@ -107,16 +107,19 @@ mod tests {
         let mut group = Group::new(
             1,
             creator,
+            Address::generate(&env),
             10_000_000, // 1 XLM
             604800,     // 1 week
             3,          // 3 members
             1234567890,
         );
 
+        start(&mut group);
+
         assert_eq!(group.current_cycle, 0);
         assert!(group.is_active);
 
-        let result = advance_group_cycle_logic(&mut group);
+        let result = advance_group_cycle_logic(&env, &mut group);
 
         assert!(result.is_ok());
         assert_eq!(group.current_cycle, 1);
@@ -131,15 +134,18 @@ mod tests {
         let mut group = Group::new(
             1,
             creator,
+            Address::generate(&env),
             10_000_000,
             604800,
             3,
             1234567890,
         );
 
+        start(&mut group);
+
         // Advance through all cycles
         for i in 0..3 {
-            let result = advance_group_cycle_logic(&mut group);
+            let result = advance_group_cycle_logic(&env, &mut group);
             assert!(result.is_ok());
             assert_eq!(group.current_cycle, i + 1);
         }
@@ -157,17 +163,20 @@ mod tests {
         let mut group = Group::new(
             1,
             creator,
+            Address::generate(&env),
             10_000_000,
             604800,
             2,
             1234567890,
         );
 
+        start(&mut group);
+
         // Advance to completion
         group.current_cycle = 2;
         group.is_active = false;
 
-        let result = advance_group_cycle_logic(&mut group);
+        let result = advance_group_cycle_logic(&env, &mut group);
 
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), StellarSaveError::InvalidState);
@@ -181,16 +190,19 @@ mod tests {
         let mut group = Group::new(
             1,
             creator,
+            Address::generate(&env),
             10_000_000,
             604800,
             2,
             1234567890,
         );
 
+        start(&mut group);
+
         // Advance to the final cycle
         group.current_cycle = 1;
 
-        let result = advance_group_cycle_logic(&mut group);
+        let result = advance_group_cycle_logic(&env, &mut group);
 
         assert!(result.is_ok());
         assert!(group.is_complete());
@@ -205,14 +217,17 @@ mod tests {
         let mut group = Group::new(
             1,
             creator,
+            Address::generate(&env),
             10_000_000,
             604800,
             5,
             1234567890,
         );
 
+        start(&mut group);
+
         // Advance from cycle 0 to 1 (not completion)
-        let result = advance_group_cycle_logic(&mut group);
+        let result = advance_group_cycle_logic(&env, &mut group);
 
         assert!(result.is_ok());
         assert_eq!(group.current_cycle, 1);
@@ -231,13 +246,16 @@ mod tests {
         let mut group = Group::new(
             1,
             creator.clone(),
+            Address::generate(&env),
             original_contribution,
             original_cycle_duration,
             original_max_members,
             1234567890,
         );
 
-        advance_group_cycle_logic(&mut group).unwrap();
+        start(&mut group);
+
+        advance_group_cycle_logic(&env, &mut group).unwrap();
 
         // Verify immutable properties are unchanged
         assert_eq!(group.contribution_amount, original_contribution);
@@ -251,25 +269,27 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
 
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 4, 1234567890);
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 4, 1234567890);
+
+        start(&mut group);
 
         // Verify cycle progression
         assert_eq!(group.current_cycle, 0);
         assert!(!group.is_complete());
 
-        advance_group_cycle_logic(&mut group).unwrap();
+        advance_group_cycle_logic(&env, &mut group).unwrap();
         assert_eq!(group.current_cycle, 1);
         assert!(!group.is_complete());
 
-        advance_group_cycle_logic(&mut group).unwrap();
+        advance_group_cycle_logic(&env, &mut group).unwrap();
         assert_eq!(group.current_cycle, 2);
         assert!(!group.is_complete());
 
-        advance_group_cycle_logic(&mut group).unwrap();
+        advance_group_cycle_logic(&env, &mut group).unwrap();
         assert_eq!(group.current_cycle, 3);
         assert!(!group.is_complete());
 
-        advance_group_cycle_logic(&mut group).unwrap();
+        advance_group_cycle_logic(&env, &mut group).unwrap();
         assert_eq!(group.current_cycle, 4);
         assert!(group.is_complete());
     }
@@ -279,10 +299,12 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
 
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 2, 1234567890);
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 2, 1234567890);
+
+        start(&mut group);
         group.current_cycle = 2; // Already complete
 
-        let result = advance_group_cycle_logic(&mut group);
+        let result = advance_group_cycle_logic(&env, &mut group);
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), StellarSaveError::InvalidState);
     }
@@ -22,6 +22,10 @@ pub enum StellarSaveError {
     /// Error Code: 1003
     InvalidState = 1003,
     
+    /// The token is not on the contract's allowlist of group assets.
+    /// Error Code: 1004
+    TokenNotAllowed = 1004,
+    
     // Member-related errors (2000-2999)
     /// The address is already a member of this group.
     /// Error Code: 2001
@@ -52,6 +56,14 @@ pub enum StellarSaveError {
     /// Error Code: 3004
     ContributionNotFound = 3004,
     
+    /// The contribution deadline for the cycle has passed.
+    /// Error Code: 3005
+    CycleClosed = 3005,
+    
+    /// The cycle is still accepting contributions.
+    /// Error Code: 3006
+    CycleStillOpen = 3006,
+    
     // Payout-related errors (4000-4999)
     /// The payout operation failed due to insufficient funds or transfer error.
     /// Error Code: 4001
@@ -77,6 +89,14 @@ pub enum StellarSaveError {
     /// Added for ID Generation: The counter has reached its maximum limit.
     /// Error Code: 9003
     Overflow = 9003,
+
+    /// The contract has already been initialized.
+    /// Error Code: 9004
+    AlreadyInitialized = 9004,
+
+    /// The contract has not been initialized with an admin and configuration.
+    /// Error Code: 9005
+    NotInitialized = 9005,
 }
 
 impl StellarSaveError {
@@ -96,6 +116,9 @@ impl StellarSaveError {
             StellarSaveError::InvalidState => {
                 "The group is not in a valid state for this operation. Check group status."
             }
+            StellarSaveError::TokenNotAllowed => {
+                "The token is not in the allowed list of group assets. Choose a supported token."
+            }
             
             // Member-related errors
             StellarSaveError::AlreadyMember => {
@@ -121,6 +144,12 @@ impl StellarSaveError {
             StellarSaveError::ContributionNotFound => {
                 "The contribution record was not found for the specified member and cycle."
             }
+            StellarSaveError::CycleClosed => {
+                "The contribution deadline for this cycle has passed. Late contributions are not accepted."
+            }
+            StellarSaveError::CycleStillOpen => {
+                "The cycle is still accepting contributions. Wait until the deadline and grace period have passed."
+            }
             
             // Payout-related errors
             StellarSaveError::PayoutFailed => {
@@ -143,6 +172,12 @@ impl StellarSaveError {
             StellarSaveError::Overflow => {
                 "The ID counter has reached its maximum limit. No more IDs can be generated."
             }
+            StellarSaveError::AlreadyInitialized => {
+                "The contract has already been initialized."
+            }
+            StellarSaveError::NotInitialized => {
+                "The contract has not been initialized. Call initialize first."
+            }
         }
     }
     
@@ -206,6 +241,7 @@ mod tests {
         assert_eq!(StellarSaveError::GroupNotFound.code(), 1001);
         assert_eq!(StellarSaveError::GroupFull.code(), 1002);
         assert_eq!(StellarSaveError::InvalidState.code(), 1003);
+        assert_eq!(StellarSaveError::TokenNotAllowed.code(), 1004);
         
         assert_eq!(StellarSaveError::AlreadyMember.code(), 2001);
         assert_eq!(StellarSaveError::NotMember.code(), 2002);
@@ -214,6 +250,8 @@ mod tests {
         assert_eq!(StellarSaveError::InvalidAmount.code(), 3001);
         assert_eq!(StellarSaveError::AlreadyContributed.code(), 3002);
         assert_eq!(StellarSaveError::CycleNotComplete.code(), 3003);
+        assert_eq!(StellarSaveError::CycleClosed.code(), 3005);
+        assert_eq!(StellarSaveError::CycleStillOpen.code(), 3006);
         
         assert_eq!(StellarSaveError::PayoutFailed.code(), 4001);
         assert_eq!(StellarSaveError::PayoutAlreadyProcessed.code(), 4002);
@@ -221,6 +259,9 @@ mod tests {
         
         assert_eq!(StellarSaveError::InternalError.code(), 9001);
         assert_eq!(StellarSaveError::DataCorruption.code(), 9002);
+        assert_eq!(StellarSaveError::Overflow.code(), 9003);
+        assert_eq!(StellarSaveError::AlreadyInitialized.code(), 9004);
+        assert_eq!(StellarSaveError::NotInitialized.code(), 9005);
     }
 
     #[test]
@@ -234,12 +275,15 @@ mod tests {
         
         assert_eq!(StellarSaveError::InvalidAmount.category(), ErrorCategory::Contribution);
         assert_eq!(StellarSaveError::AlreadyContributed.category(), ErrorCategory::Contribution);
+        assert_eq!(StellarSaveError::CycleStillOpen.category(), ErrorCategory::Contribution);
         
         assert_eq!(StellarSaveError::PayoutFailed.category(), ErrorCategory::Payout);
         assert_eq!(StellarSaveError::PayoutAlreadyProcessed.category(), ErrorCategory::Payout);
         
         assert_eq!(StellarSaveError::InternalError.category(), ErrorCategory::System);
         assert_eq!(StellarSaveError::DataCorruption.category(), ErrorCategory::System);
+        assert_eq!(StellarSaveError::AlreadyInitialized.category(), ErrorCategory::System);
+        assert_eq!(StellarSaveError::NotInitialized.category(), ErrorCategory::System);
     }
 
     #[test]
@@ -249,17 +293,24 @@ mod tests {
             StellarSaveError::GroupNotFound,
             StellarSaveError::GroupFull,
             StellarSaveError::InvalidState,
+            StellarSaveError::TokenNotAllowed,
             StellarSaveError::AlreadyMember,
             StellarSaveError::NotMember,
             StellarSaveError::Unauthorized,
             StellarSaveError::InvalidAmount,
             StellarSaveError::AlreadyContributed,
             StellarSaveError::CycleNotComplete,
+            StellarSaveError::ContributionNotFound,
+            StellarSaveError::CycleClosed,
+            StellarSaveError::CycleStillOpen,
             StellarSaveError::PayoutFailed,
             StellarSaveError::PayoutAlreadyProcessed,
             StellarSaveError::InvalidRecipient,
             StellarSaveError::InternalError,
             StellarSaveError::DataCorruption,
+            StellarSaveError::Overflow,
+            StellarSaveError::AlreadyInitialized,
+            StellarSaveError::NotInitialized,
         ];
 
         for error in &errors {
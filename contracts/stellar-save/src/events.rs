@@ -1,4 +1,4 @@
-use soroban_sdk::{contracttype, Address, Env};
+use soroban_sdk::{contracttype, Address, BytesN, Env, Vec};
 
 /// Event emitted when a new savings group is created.
 #[contracttype]
@@ -44,6 +44,18 @@ pub struct ContributionMade {
     pub contributed_at: u64,
 }
 
+/// Event emitted when a member is marked as having missed a cycle's contribution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MemberDefaulted {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub default_count: u32,
+    pub slashed: i128,
+    pub defaulted_at: u64,
+}
+
 /// Event emitted when a payout is executed.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -77,6 +89,133 @@ pub struct GroupStatusChanged {
     pub changed_at: u64,
 }
 
+/// Event emitted when the contract is initialized with its admin and configuration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractInitialized {
+    pub admin: Address,
+    pub initialized_at: u64,
+}
+
+/// Event emitted when the admin replaces the global configuration.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ConfigUpdated {
+    pub admin: Address,
+    pub updated_at: u64,
+}
+
+/// Event emitted when the admin proposes a new admin.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminProposed {
+    pub current_admin: Address,
+    pub proposed_admin: Address,
+    pub proposed_at: u64,
+}
+
+/// Event emitted when a proposed admin accepts the role.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminTransferred {
+    pub old_admin: Address,
+    pub new_admin: Address,
+    pub transferred_at: u64,
+}
+
+/// Event emitted when the admin replaces the contract code.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractUpgraded {
+    pub admin: Address,
+    pub new_wasm_hash: BytesN<32>,
+    pub upgraded_at: u64,
+}
+
+/// Event emitted when stored data is migrated to a new schema version.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractMigrated {
+    pub from_version: u32,
+    pub to_version: u32,
+    pub migrated_at: u64,
+}
+
+/// Event emitted when a member bids for an auction cycle's payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BidPlaced {
+    pub group_id: u64,
+    pub member: Address,
+    pub cycle: u32,
+    pub discount: i128,
+    pub placed_at: u64,
+}
+
+/// Event emitted when an auction cycle is settled at payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AuctionSettled {
+    pub group_id: u64,
+    pub winner: Address,
+    pub cycle: u32,
+    pub discount: i128,
+    pub dividend: i128,
+    pub settled_at: u64,
+}
+
+/// Event emitted when a member files a hardship request for early payout.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriorityRequested {
+    pub group_id: u64,
+    pub requester: Address,
+    pub cycle: u32,
+    pub requested_at: u64,
+}
+
+/// Event emitted when members approve or reject a hardship request.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriorityResolved {
+    pub group_id: u64,
+    pub requester: Address,
+    pub cycle: u32,
+    pub approved: bool,
+    pub resolved_at: u64,
+}
+
+/// Event emitted when two members exchange payout positions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PositionsSwapped {
+    pub group_id: u64,
+    pub from: Address,
+    pub to: Address,
+    pub from_position: u32,
+    pub to_position: u32,
+    pub swapped_at: u64,
+}
+
+/// Event emitted when a group's payout order is assigned.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutOrderAssigned {
+    pub group_id: u64,
+    pub order: Vec<Address>,
+    pub assigned_at: u64,
+}
+
+/// Event emitted when a completed group's reserve is shared out to its members.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReserveDistributed {
+    pub group_id: u64,
+    pub total: i128,
+    pub share: i128,
+    pub distributed_at: u64,
+}
+
 /// Utility functions for emitting events.
 pub struct EventEmitter;
 
@@ -153,6 +292,26 @@ impl EventEmitter {
         env.events().publish(("contribution_made",), event);
     }
     
+    pub fn emit_member_defaulted(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        default_count: u32,
+        slashed: i128,
+        defaulted_at: u64,
+    ) {
+        let event = MemberDefaulted {
+            group_id,
+            member,
+            cycle,
+            default_count,
+            slashed,
+            defaulted_at,
+        };
+        env.events().publish(("member_defaulted",), event);
+    }
+    
     pub fn emit_payout_executed(
         env: &Env,
         group_id: u64,
@@ -206,6 +365,181 @@ impl EventEmitter {
         };
         env.events().publish(("group_status_changed",), event);
     }
+
+    pub fn emit_contract_initialized(env: &Env, admin: Address, initialized_at: u64) {
+        let event = ContractInitialized {
+            admin,
+            initialized_at,
+        };
+        env.events().publish(("contract_initialized",), event);
+    }
+
+    pub fn emit_config_updated(env: &Env, admin: Address, updated_at: u64) {
+        let event = ConfigUpdated { admin, updated_at };
+        env.events().publish(("config_updated",), event);
+    }
+
+    pub fn emit_admin_proposed(
+        env: &Env,
+        current_admin: Address,
+        proposed_admin: Address,
+        proposed_at: u64,
+    ) {
+        let event = AdminProposed {
+            current_admin,
+            proposed_admin,
+            proposed_at,
+        };
+        env.events().publish(("admin_proposed",), event);
+    }
+
+    pub fn emit_admin_transferred(
+        env: &Env,
+        old_admin: Address,
+        new_admin: Address,
+        transferred_at: u64,
+    ) {
+        let event = AdminTransferred {
+            old_admin,
+            new_admin,
+            transferred_at,
+        };
+        env.events().publish(("admin_transferred",), event);
+    }
+
+    pub fn emit_contract_upgraded(
+        env: &Env,
+        admin: Address,
+        new_wasm_hash: BytesN<32>,
+        upgraded_at: u64,
+    ) {
+        let event = ContractUpgraded {
+            admin,
+            new_wasm_hash,
+            upgraded_at,
+        };
+        env.events().publish(("contract_upgraded",), event);
+    }
+
+    pub fn emit_contract_migrated(env: &Env, from_version: u32, to_version: u32, migrated_at: u64) {
+        let event = ContractMigrated {
+            from_version,
+            to_version,
+            migrated_at,
+        };
+        env.events().publish(("contract_migrated",), event);
+    }
+
+    pub fn emit_bid_placed(
+        env: &Env,
+        group_id: u64,
+        member: Address,
+        cycle: u32,
+        discount: i128,
+        placed_at: u64,
+    ) {
+        let event = BidPlaced {
+            group_id,
+            member,
+            cycle,
+            discount,
+            placed_at,
+        };
+        env.events().publish(("bid_placed",), event);
+    }
+
+    pub fn emit_auction_settled(
+        env: &Env,
+        group_id: u64,
+        winner: Address,
+        cycle: u32,
+        discount: i128,
+        dividend: i128,
+        settled_at: u64,
+    ) {
+        let event = AuctionSettled {
+            group_id,
+            winner,
+            cycle,
+            discount,
+            dividend,
+            settled_at,
+        };
+        env.events().publish(("auction_settled",), event);
+    }
+
+    pub fn emit_priority_requested(
+        env: &Env,
+        group_id: u64,
+        requester: Address,
+        cycle: u32,
+        requested_at: u64,
+    ) {
+        let event = PriorityRequested {
+            group_id,
+            requester,
+            cycle,
+            requested_at,
+        };
+        env.events().publish(("priority_requested",), event);
+    }
+
+    pub fn emit_priority_resolved(
+        env: &Env,
+        group_id: u64,
+        requester: Address,
+        cycle: u32,
+        approved: bool,
+        resolved_at: u64,
+    ) {
+        let event = PriorityResolved {
+            group_id,
+            requester,
+            cycle,
+            approved,
+            resolved_at,
+        };
+        env.events().publish(("priority_resolved",), event);
+    }
+
+    pub fn emit_positions_swapped(
+        env: &Env,
+        group_id: u64,
+        from: Address,
+        to: Address,
+        from_position: u32,
+        to_position: u32,
+        swapped_at: u64,
+    ) {
+        let event = PositionsSwapped {
+            group_id,
+            from,
+            to,
+            from_position,
+            to_position,
+            swapped_at,
+        };
+        env.events().publish(("positions_swapped",), event);
+    }
+
+    pub fn emit_payout_order_assigned(env: &Env, group_id: u64, order: Vec<Address>, assigned_at: u64) {
+        let event = PayoutOrderAssigned {
+            group_id,
+            order,
+            assigned_at,
+        };
+        env.events().publish(("payout_order_assigned",), event);
+    }
+
+    pub fn emit_reserve_distributed(env: &Env, group_id: u64, total: i128, share: i128, distributed_at: u64) {
+        let event = ReserveDistributed {
+            group_id,
+            total,
+            share,
+            distributed_at,
+        };
+        env.events().publish(("reserve_distributed",), event);
+    }
 }
 
 #[cfg(test)]
@@ -1,104 +1,83 @@
 use soroban_sdk::{contracttype, Address};
-use core::fmt;
-
-/// Represents the lifecycle states of a savings group.
-///
-/// Groups progress through these states during their lifetime:
-/// - Pending: Newly created, waiting for minimum members
-/// - Active: Accepting contributions and processing payouts
-/// - Paused: Temporarily suspended (can be resumed)
-/// - Completed: All cycles finished successfully
-/// - Cancelled: Permanently terminated before completion
+use crate::status::{GroupStatus, StatusError};
+use crate::AssignmentMode;
+
+/// Penalty charged on contributions made after a cycle's deadline but within
+/// the group's grace period.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
-pub enum GroupStatus {
-    /// Group is created but not yet active.
-    /// Waiting for minimum members or creator activation.
-    Pending,
-
-    /// Group is actively running cycles.
-    /// Members can contribute and payouts are processed.
-    Active,
-
-    /// Group is temporarily paused.
-    /// No contributions accepted, but can be resumed.
-    Paused,
-
-    /// Group has completed all cycles successfully.
-    /// All members have received their payouts.
-    Completed,
-
-    /// Group was cancelled before completion.
-    /// Funds should be returned to contributors.
-    Cancelled,
-}
-
-impl GroupStatus {
-    /// Validates if a state transition is allowed.
-    ///
-    /// Valid transitions:
-    /// - Pending → Active, Cancelled
-    /// - Active → Paused, Completed, Cancelled
-    /// - Paused → Active, Cancelled
-    /// - Completed → (no transitions allowed)
-    /// - Cancelled → (no transitions allowed)
-    pub fn can_transition_to(&self, new_status: &GroupStatus) -> bool {
-        // Same state is always valid
-        if self == new_status {
-            return true;
-        }
-        
-        match (self, new_status) {
-            // From Pending
-            (GroupStatus::Pending, GroupStatus::Active) => true,
-            (GroupStatus::Pending, GroupStatus::Cancelled) => true,
-
-            // From Active
-            (GroupStatus::Active, GroupStatus::Paused) => true,
-            (GroupStatus::Active, GroupStatus::Completed) => true,
-            (GroupStatus::Active, GroupStatus::Cancelled) => true,
+pub enum LatePenalty {
+    /// Late contributions are accepted without a penalty.
+    None,
 
-            // From Paused
-            (GroupStatus::Paused, GroupStatus::Active) => true,
-            (GroupStatus::Paused, GroupStatus::Cancelled) => true,
+    /// Fixed penalty amount in the group's token units.
+    Flat(i128),
 
-            // Terminal states cannot transition to other states
-            (GroupStatus::Completed, _) => false,
-            (GroupStatus::Cancelled, _) => false,
+    /// Penalty as basis points of `contribution_amount` (100 = 1%).
+    BasisPoints(u32),
+}
 
-            // All other transitions are invalid
-            _ => false,
+impl LatePenalty {
+    /// Returns true if the penalty is non-negative and at most 100% of
+    /// `contribution_amount`.
+    pub fn is_valid(&self, contribution_amount: i128) -> bool {
+        match self {
+            LatePenalty::None => true,
+            LatePenalty::Flat(amount) => *amount >= 0 && *amount <= contribution_amount,
+            LatePenalty::BasisPoints(bps) => *bps <= 10_000,
         }
     }
+}
 
-    /// Returns true if the group can accept contributions in this state.
-    pub fn accepts_contributions(&self) -> bool {
-        matches!(self, GroupStatus::Active)
-    }
+/// Rules a creator sets for how a group handles lateness, collateral and
+/// payout assignment, passed to `create_group`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupPolicy {
+    /// Seconds after each cycle deadline during which late contributions
+    /// are still accepted (0 rejects contributions after the deadline).
+    pub grace_period: u64,
+
+    /// Penalty charged on contributions made within the grace period.
+    pub late_penalty: LatePenalty,
+
+    /// Collateral each member locks on join, as a multiple of
+    /// `contribution_amount` (0 for no collateral).
+    pub collateral_multiplier: u32,
+
+    /// Mode `assign_payout_positions` must use, fixed when the group is
+    /// created so the creator cannot pick one after seeing revealed seeds.
+    /// For `Manual` the positions are given at assignment.
+    pub assignment_mode: AssignmentMode,
+
+    /// Seconds after creation during which members may commit seeds for a
+    /// `Random` or `CommitReveal` draw; the reveal phase that follows lasts
+    /// as long. 0 means each phase only ends once every member has taken
+    /// part in it.
+    pub commit_period: u64,
+}
 
-    /// Returns true if the group can process payouts in this state.
-    pub fn can_process_payouts(&self) -> bool {
-        matches!(self, GroupStatus::Active)
+impl GroupPolicy {
+    /// Creates a policy from its grace period, late penalty and collateral
+    /// multiplier, with `Sequential` payout assignment.
+    pub fn new(grace_period: u64, late_penalty: LatePenalty, collateral_multiplier: u32) -> Self {
+        Self {
+            grace_period,
+            late_penalty,
+            collateral_multiplier,
+            assignment_mode: AssignmentMode::Sequential,
+            commit_period: 0,
+        }
     }
 
-    /// Returns true if this is a terminal state (no further transitions allowed).
-    pub fn is_terminal(&self) -> bool {
-        matches!(self, GroupStatus::Completed | GroupStatus::Cancelled)
+    /// Sets the payout assignment mode and, for seeded draws, the commit period.
+    pub fn with_assignment_mode(mut self, assignment_mode: AssignmentMode, commit_period: u64) -> Self {
+        self.assignment_mode = assignment_mode;
+        self.commit_period = commit_period;
+        self
     }
 }
 
-impl fmt::Display for GroupStatus {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        let status_str = match self {
-            GroupStatus::Pending => "Pending",
-            GroupStatus::Active => "Active",
-            GroupStatus::Paused => "Paused",
-            GroupStatus::Completed => "Completed",
-            GroupStatus::Cancelled => "Cancelled",
-        };
-        write!(f, "{}", status_str)
-    }
-}
 /// Core Group data structure representing a rotational savings group (ROSCA).
 /// 
 /// A Group manages the configuration and state of a savings circle where members
@@ -115,7 +94,12 @@ pub struct Group {
     /// and potentially managing group settings.
     pub creator: Address,
 
-    /// Fixed contribution amount in stroops (1 XLM = 10^7 stroops).
+    /// Token contract (Stellar Asset Contract) the group is denominated in.
+    /// Used for every contribution, payout and refund of this group.
+    pub token: Address,
+
+    /// Fixed contribution amount in the token's smallest unit
+    /// (e.g. stroops for XLM, where 1 XLM = 10^7 stroops).
     /// All members must contribute this exact amount each cycle.
     /// Must be greater than 0.
     pub contribution_amount: i128,
@@ -147,14 +131,12 @@ pub struct Group {
     pub current_cycle: u32,
 
     /// Whether the group is currently active and accepting contributions.
-    /// Set to false when:
-    /// - Group is paused by admin/creator
-    /// - All cycles are complete
-    /// - Group encounters an error state
+    /// Mirrors `status == GroupStatus::Active`; maintained by `set_status`.
     pub is_active: bool,
 
-    /// Status of the group (Active or Completed).
-    /// Replaces is_active for more explicit state management.
+    /// Lifecycle status of the group.
+    /// The single source of truth for the group's state; only changed through
+    /// `set_status` (via `status::transition` in contract code).
     pub status: GroupStatus,
 
     /// Timestamp when the group was created (Unix timestamp in seconds).
@@ -164,10 +146,28 @@ pub struct Group {
     /// Whether the group has been started (first cycle activated).
     pub started: bool,
 
+    /// Grace window in seconds after a cycle deadline during which late
+    /// contributions are still accepted (with `late_penalty` applied).
+    pub grace_period: u64,
+
+    /// Penalty charged on contributions made during the grace window.
+    pub late_penalty: LatePenalty,
+
+    /// Collateral each member locks on join, as a multiple of
+    /// `contribution_amount`. Zero means no collateral is required.
+    pub collateral_multiplier: u32,
+
     /// Timestamp when the group was activated (Unix timestamp in seconds).
     /// Used for tracking when the first cycle started.
     /// Only set when started is true.
     pub started_at: u64,
+
+    /// Timestamp when the group was last paused, or 0 if it is not paused.
+    pub paused_at: u64,
+
+    /// Total seconds the group has spent paused since activation.
+    /// Excluded from cycle timing so pauses don't eat into a cycle.
+    pub total_paused: u64,
 }
 
 impl Group {
@@ -176,26 +176,26 @@ impl Group {
     /// # Arguments
     /// * `id` - Unique group identifier
     /// * `creator` - Address of the group creator
-    /// * `contribution_amount` - Amount each member contributes per cycle (in stroops)
+    /// * `token` - Token contract the group is denominated in
+    /// * `contribution_amount` - Amount each member contributes per cycle (in token units)
     /// * `cycle_duration` - Duration of each cycle in seconds
     /// * `max_members` - Maximum number of members allowed
-    /// * `min_members` - Minimum number of members required to activate the group
     /// * `created_at` - Creation timestamp
+    ///
+    /// The group needs 2 members to activate; see `with_min_members`.
     /// 
     /// # Panics
     /// Panics if validation constraints are violated:
     /// - contribution_amount must be > 0
     /// - cycle_duration must be > 0
     /// - max_members must be >= 2
-    /// - min_members must be >= 2
-    /// - min_members must be <= max_members
     pub fn new(
         id: u64,
         creator: Address,
+        token: Address,
         contribution_amount: i128,
         cycle_duration: u64,
         max_members: u32,
-        min_members: u32,
         created_at: u64,
     ) -> Self {
         // Validate contribution amount
@@ -216,35 +216,50 @@ impl Group {
             "max_members must be at least 2"
         );
 
-        // Validate min members (minimum 2 for a meaningful ROSCA)
-        assert!(
-            min_members >= 2,
-            "min_members must be at least 2"
-        );
-
-        // Validate min_members <= max_members
-        assert!(
-            min_members <= max_members,
-            "min_members must be less than or equal to max_members"
-        );
-
         Self {
             id,
             creator,
+            token,
             contribution_amount,
             cycle_duration,
             max_members,
-            min_members,
+            min_members: 2,
             member_count: 0,
             current_cycle: 0,
-            is_active: true,
-            status: GroupStatus::Active,
+            is_active: false,
+            status: GroupStatus::Pending,
             created_at,
             started: false,
             started_at: 0,
+            grace_period: 0,
+            late_penalty: LatePenalty::None,
+            collateral_multiplier: 0,
+            paused_at: 0,
+            total_paused: 0,
         }
     }
 
+    /// Sets the number of members required to activate the group.
+    ///
+    /// # Panics
+    /// Panics if min_members is below 2 or above max_members.
+    pub fn with_min_members(mut self, min_members: u32) -> Self {
+        // Validate min members (minimum 2 for a meaningful ROSCA)
+        assert!(
+            min_members >= 2,
+            "min_members must be at least 2"
+        );
+
+        // Validate min_members <= max_members
+        assert!(
+            min_members <= self.max_members,
+            "min_members must be less than or equal to max_members"
+        );
+
+        self.min_members = min_members;
+        self
+    }
+
     /// Checks if the group has completed all cycles.
     /// A group is complete when current_cycle equals max_members
     /// or when status is Completed.
@@ -252,97 +267,104 @@ impl Group {
         self.current_cycle >= self.max_members || self.status == GroupStatus::Completed
     }
 
-    /// Marks the group as completed.
-    /// This should be called after verifying all payouts have been made.
-    /// Emits a GroupEvent::Completed event.
+    /// Applies a status change validated against the transition table.
+    /// 
+    /// Keeps `is_active` in sync and records lifecycle timing: activation sets
+    /// `started`/`started_at`, pausing sets `paused_at`, and resuming adds the
+    /// paused interval to `total_paused`. Does not persist or emit events; use
+    /// `status::transition` for that.
     /// 
     /// # Arguments
-    /// * `env` - Soroban environment for event emission
+    /// * `new_status` - The target status
+    /// * `timestamp` - Current timestamp
     /// 
-    /// # Panics
-    /// Panics if the group is already complete.
-    pub fn complete(&mut self, env: &soroban_sdk::Env) {
-        assert!(!self.is_complete(), "group is already complete");
-        self.status = GroupStatus::Completed;
-        self.is_active = false;
-        
-        // Emit completion event
-        Self::emit_completed_event(env, self.id);
-    }
+    /// # Returns
+    /// The previous status, or a `StatusError` if the transition is not allowed.
+    pub fn set_status(
+        &mut self,
+        new_status: GroupStatus,
+        timestamp: u64,
+    ) -> Result<GroupStatus, StatusError> {
+        let old_status = self.status;
+        old_status.can_transition_to(new_status)?;
+
+        match (old_status, new_status) {
+            (GroupStatus::Pending, GroupStatus::Active) => {
+                self.started = true;
+                self.started_at = timestamp;
+            }
+            (GroupStatus::Active, GroupStatus::Paused) => {
+                self.paused_at = timestamp;
+            }
+            (GroupStatus::Paused, GroupStatus::Active) => {
+                self.total_paused = self
+                    .total_paused
+                    .saturating_add(timestamp.saturating_sub(self.paused_at));
+                self.paused_at = 0;
+            }
+            _ => {}
+        }
 
-    /// Emits a GroupEvent::Completed event.
-    /// 
-    /// # Arguments
-    /// * `env` - Soroban environment
-    /// * `group_id` - The ID of the completed group
-    fn emit_completed_event(env: &soroban_sdk::Env, group_id: u64) {
-        env.events().publish(
-            (soroban_sdk::Symbol::new(env, "group_completed"),),
-            group_id,
-        );
+        self.status = new_status;
+        self.is_active = new_status == GroupStatus::Active;
+        Ok(old_status)
     }
 
     /// Advances to the next cycle.
-    /// Should be called after a successful payout.
-    /// Emits a GroupEvent::Completed event when the group becomes complete.
-    /// 
-    /// # Arguments
-    /// * `env` - Soroban environment for event emission
+    /// Should be called after a successful payout. Completing the group is a
+    /// separate status transition.
     /// 
     /// # Panics
     /// Panics if the group is already complete.
-    pub fn advance_cycle(&mut self, env: &soroban_sdk::Env) {
+    pub fn advance_cycle(&mut self) {
         assert!(!self.is_complete(), "group is already complete");
         self.current_cycle += 1;
-        
-        // Mark as complete if we've reached the final cycle
-        if self.is_complete() {
-            self.status = GroupStatus::Completed;
-            self.is_active = false;
-            
-            // Emit completion event
-            Self::emit_completed_event(env, self.id);
-        }
     }
 
-    /// Deactivates the group, preventing further contributions.
-    pub fn deactivate(&mut self) {
-        self.is_active = false;
+    /// Returns true once every member of a started group has had their payout cycle.
+    pub fn rotation_finished(&self) -> bool {
+        self.started && self.current_cycle >= self.member_count
     }
 
-    /// Reactivates the group if it's not complete.
-    /// 
-    /// # Panics
-    /// Panics if attempting to reactivate a completed group.
-    pub fn reactivate(&mut self) {
-        assert!(!self.is_complete(), "cannot reactivate a completed group");
-        self.is_active = true;
-        self.status = GroupStatus::Active;
+    /// Returns the deadline (Unix timestamp in seconds) for contributions to a cycle.
+    ///
+    /// Cycle `n` runs from `started_at + n * cycle_duration` until
+    /// `started_at + (n + 1) * cycle_duration`, shifted by `total_paused`.
+    pub fn cycle_deadline(&self, cycle: u32) -> u64 {
+        self.started_at
+            .saturating_add(self.total_paused)
+            .saturating_add((cycle as u64 + 1).saturating_mul(self.cycle_duration))
     }
 
-    /// Activates the group (starts the first cycle) once minimum members have joined.
-    /// 
-    /// # Arguments
-    /// * `timestamp` - Current timestamp when activation occurs
-    /// 
+    /// Sets the grace window and penalty applied to late contributions.
+    ///
     /// # Panics
-    /// Panics if:
-    /// - Group has already been started
-    /// - Minimum member count has not been reached
-    pub fn activate(&mut self, timestamp: u64) {
-        // Check if already started
-        assert!(!self.started, "group has already been started");
-        
-        // Check if minimum members have joined
+    /// Panics if the penalty is invalid or the grace period is longer than a cycle.
+    pub fn set_late_policy(&mut self, grace_period: u64, late_penalty: LatePenalty) {
+        assert!(late_penalty.is_valid(self.contribution_amount), "late_penalty is invalid");
         assert!(
-            self.member_count >= self.min_members,
-            "minimum members ({}) required to activate, currently have {}",
-            self.min_members,
-            self.member_count
+            grace_period <= self.cycle_duration,
+            "grace_period must not exceed cycle_duration"
         );
-        
-        self.started = true;
-        self.started_at = timestamp;
+
+        self.grace_period = grace_period;
+        self.late_penalty = late_penalty;
+    }
+
+    /// Returns the penalty charged on a late contribution.
+    pub fn late_penalty_amount(&self) -> i128 {
+        match self.late_penalty {
+            LatePenalty::None => 0,
+            LatePenalty::Flat(amount) => amount,
+            LatePenalty::BasisPoints(bps) => self.contribution_amount * bps as i128 / 10_000,
+        }
+    }
+
+    /// Returns the collateral each member must lock when joining.
+    ///
+    /// Returns `None` if `contribution_amount × collateral_multiplier` overflows.
+    pub fn collateral_amount(&self) -> Option<i128> {
+        self.contribution_amount.checked_mul(self.collateral_multiplier as i128)
     }
 
     /// Checks if the group has met the minimum member requirement for activation.
@@ -383,27 +405,29 @@ mod tests {
     fn test_group_creation() {
         let env = Env::default();
         let creator = Address::generate(&env);
+        let token = Address::generate(&env);
         
         let group = Group::new(
             1,
             creator.clone(),
+            token.clone(),
             10_000_000, // 1 XLM
             604800,     // 1 week
             5,          // 5 members
-            2,          // 2 min members
             1234567890,
         );
 
         assert_eq!(group.id, 1);
         assert_eq!(group.creator, creator);
+        assert_eq!(group.token, token);
         assert_eq!(group.contribution_amount, 10_000_000);
         assert_eq!(group.cycle_duration, 604800);
         assert_eq!(group.max_members, 5);
         assert_eq!(group.min_members, 2);
         assert_eq!(group.member_count, 0);
         assert_eq!(group.current_cycle, 0);
-        assert_eq!(group.is_active, true);
-        assert_eq!(group.status, GroupStatus::Active);
+        assert_eq!(group.is_active, false);
+        assert_eq!(group.status, GroupStatus::Pending);
         assert_eq!(group.created_at, 1234567890);
     }
 
@@ -413,7 +437,7 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        Group::new(1, creator, 10_000_000, 604800, 5, 1, 1234567890);
+        Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 5, 1234567890).with_min_members(1);
     }
 
     #[test]
@@ -422,7 +446,7 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        Group::new(1, creator, 10_000_000, 604800, 3, 5, 1234567890);
+        Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 3, 1234567890).with_min_members(5);
     }
 
     #[test]
@@ -431,7 +455,7 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        Group::new(1, creator, 0, 604800, 5, 2, 1234567890);
+        Group::new(1, creator, Address::generate(&env), 0, 604800, 5, 1234567890);
     }
 
     #[test]
@@ -440,7 +464,7 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        Group::new(1, creator, 10_000_000, 0, 5, 2, 1234567890);
+        Group::new(1, creator, Address::generate(&env), 10_000_000, 0, 5, 1234567890);
     }
 
     #[test]
@@ -449,7 +473,7 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        Group::new(1, creator, 10_000_000, 604800, 1, 2, 1234567890);
+        Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 1, 1234567890);
     }
 
     #[test]
@@ -457,7 +481,7 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 3, 1234567890);
         
         assert!(!group.is_complete());
         
@@ -468,31 +492,32 @@ mod tests {
         assert!(group.is_complete());
     }
 
+    /// Creates a group with `members` joined and activated at `timestamp`.
+    fn active_group(env: &Env, members: u32, timestamp: u64) -> Group {
+        let mut group = Group::new(1, Address::generate(env), Address::generate(env), 10_000_000, 604800, 3, 1234567890);
+        group.member_count = members;
+        group.set_status(GroupStatus::Active, timestamp).unwrap();
+        group
+    }
+
     #[test]
     fn test_advance_cycle() {
         let env = Env::default();
-        let creator = Address::generate(&env);
-        
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
+        let mut group = active_group(&env, 3, 1_000);
         
         assert_eq!(group.current_cycle, 0);
-        assert!(group.is_active);
-        assert_eq!(group.status, GroupStatus::Active);
         
-        group.advance_cycle(&env);
+        group.advance_cycle();
         assert_eq!(group.current_cycle, 1);
-        assert!(group.is_active);
-        assert_eq!(group.status, GroupStatus::Active);
-        
-        group.advance_cycle(&env);
-        assert_eq!(group.current_cycle, 2);
-        assert!(group.is_active);
-        assert_eq!(group.status, GroupStatus::Active);
+        assert!(!group.rotation_finished());
         
-        group.advance_cycle(&env);
+        group.advance_cycle();
+        group.advance_cycle();
         assert_eq!(group.current_cycle, 3);
-        assert!(!group.is_active); // Auto-deactivated when complete
-        assert_eq!(group.status, GroupStatus::Completed); // Status set to Completed
+        assert!(group.rotation_finished());
+        
+        // Completion is a separate transition
+        assert_eq!(group.status, GroupStatus::Active);
     }
 
     #[test]
@@ -501,62 +526,87 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 2, 2, 1234567890);
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 2, 1234567890);
         group.current_cycle = 2;
         
-        group.advance_cycle(&env); // Should panic
+        group.advance_cycle(); // Should panic
+    }
+
+    #[test]
+    fn test_set_status_activate() {
+        let env = Env::default();
+        let group = active_group(&env, 2, 1_700_000_000);
+        
+        assert_eq!(group.status, GroupStatus::Active);
+        assert!(group.is_active);
+        assert!(group.started);
+        assert_eq!(group.started_at, 1_700_000_000);
     }
 
     #[test]
-    fn test_deactivate_reactivate() {
+    fn test_set_status_invalid_transition() {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 3, 1234567890);
         
-        assert!(group.is_active);
-        assert_eq!(group.status, GroupStatus::Active);
+        assert_eq!(
+            group.set_status(GroupStatus::Paused, 1_000),
+            Err(StatusError::InvalidTransition)
+        );
+        assert_eq!(group.status, GroupStatus::Pending);
+    }
+
+    #[test]
+    fn test_pause_resume_shifts_deadline() {
+        let env = Env::default();
+        let mut group = active_group(&env, 2, 1_000);
         
-        group.deactivate();
+        assert_eq!(group.set_status(GroupStatus::Paused, 2_000), Ok(GroupStatus::Active));
+        assert_eq!(group.status, GroupStatus::Paused);
         assert!(!group.is_active);
-        assert_eq!(group.status, GroupStatus::Active); // Status remains Active when just deactivated
         
-        group.reactivate();
-        assert!(group.is_active);
+        assert_eq!(group.set_status(GroupStatus::Active, 5_000), Ok(GroupStatus::Paused));
         assert_eq!(group.status, GroupStatus::Active);
+        assert!(group.is_active);
+        assert_eq!(group.total_paused, 3_000);
+        assert_eq!(group.cycle_deadline(0), 1_000 + 3_000 + 604800);
     }
 
     #[test]
-    fn test_complete_group() {
+    fn test_cancel_group() {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 3, 1234567890);
+        group.set_status(GroupStatus::Cancelled, 1_000).unwrap();
+        
+        assert_eq!(group.status, GroupStatus::Cancelled);
+        assert!(!group.is_active);
+        assert_eq!(
+            group.set_status(GroupStatus::Active, 2_000),
+            Err(StatusError::AlreadyCancelled)
+        );
+    }
+
+    #[test]
+    fn test_complete_group() {
+        let env = Env::default();
+        let mut group = active_group(&env, 3, 1_000);
         
-        // Group starts as Active
-        assert_eq!(group.status, GroupStatus::Active);
         assert!(group.is_active);
         assert!(!group.is_complete());
         
-        // Complete the group manually
-        group.complete(&env);
+        group.set_status(GroupStatus::Completed, 2_000).unwrap();
         
         // Verify group is marked as completed
         assert_eq!(group.status, GroupStatus::Completed);
         assert!(!group.is_active);
         assert!(group.is_complete());
-    }
-
-    #[test]
-    #[should_panic(expected = "group is already complete")]
-    fn test_complete_already_complete_group() {
-        let env = Env::default();
-        let creator = Address::generate(&env);
-        
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 2, 2, 1234567890);
-        group.current_cycle = 2; // Already complete via cycle advancement
-        
-        group.complete(&env); // Should panic
+        assert_eq!(
+            group.set_status(GroupStatus::Active, 3_000),
+            Err(StatusError::AlreadyCompleted)
+        );
     }
 
     #[test]
@@ -564,7 +614,7 @@ mod tests {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 3, 1234567890);
         
         // Not complete initially
         assert!(!group.is_complete());
@@ -577,118 +627,81 @@ mod tests {
     }
 
     #[test]
-    fn test_complete_after_all_cycles() {
+    fn test_cycle_deadline() {
         let env = Env::default();
-        let creator = Address::generate(&env);
-        
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 3, 2, 1234567890);
+        let group = active_group(&env, 2, 1_700_000_000);
         
-        // Advance through all cycles
-        group.advance_cycle(&env); // cycle 1
-        group.advance_cycle(&env); // cycle 2
-        group.advance_cycle(&env); // cycle 3 - complete
-        
-        // Verify group is complete
-        assert!(group.is_complete());
-        assert_eq!(group.status, GroupStatus::Completed);
-        assert!(!group.is_active);
+        assert_eq!(group.cycle_deadline(0), 1_700_000_000 + 604800);
+        assert_eq!(group.cycle_deadline(2), 1_700_000_000 + 3 * 604800);
     }
 
     #[test]
-    #[should_panic(expected = "cannot reactivate a completed group")]
-    fn test_reactivate_completed_group() {
+    fn test_late_penalty_amount() {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        let mut group = Group::new(1, creator, 10_000_000, 604800, 2, 2, 1234567890);
-        group.current_cycle = 2;
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 3, 1234567890);
+        assert_eq!(group.late_penalty_amount(), 0);
         
-        group.reactivate(); // Should panic
+        group.set_late_policy(86400, LatePenalty::Flat(500_000));
+        assert_eq!(group.grace_period, 86400);
+        assert_eq!(group.late_penalty_amount(), 500_000);
+        
+        group.set_late_policy(86400, LatePenalty::BasisPoints(250)); // 2.5%
+        assert_eq!(group.late_penalty_amount(), 250_000);
     }
 
     #[test]
-    fn test_total_pool_amount() {
+    #[should_panic(expected = "late_penalty is invalid")]
+    fn test_late_policy_flat_above_contribution() {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        let group = Group::new(1, creator, 10_000_000, 604800, 5, 2, 1234567890);
-        
-        assert_eq!(group.total_pool_amount(), 50_000_000); // 5 XLM total
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 3, 1234567890);
+        group.set_late_policy(86400, LatePenalty::Flat(10_000_001));
     }
 
     #[test]
-    fn test_validate() {
+    #[should_panic(expected = "grace_period must not exceed cycle_duration")]
+    fn test_late_policy_grace_too_long() {
         let env = Env::default();
         let creator = Address::generate(&env);
         
-        let group = Group::new(1, creator, 10_000_000, 604800, 5, 2, 1234567890);
-        assert!(group.validate());
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 3, 1234567890);
+        group.set_late_policy(604801, LatePenalty::None);
     }
 
-    // GroupStatus tests
     #[test]
-    fn test_group_status_transitions() {
-        // Test valid transitions from Pending
-        assert!(GroupStatus::Pending.can_transition_to(&GroupStatus::Active));
-        assert!(GroupStatus::Pending.can_transition_to(&GroupStatus::Cancelled));
-        assert!(!GroupStatus::Pending.can_transition_to(&GroupStatus::Paused));
-        assert!(!GroupStatus::Pending.can_transition_to(&GroupStatus::Completed));
-
-        // Test valid transitions from Active
-        assert!(GroupStatus::Active.can_transition_to(&GroupStatus::Paused));
-        assert!(GroupStatus::Active.can_transition_to(&GroupStatus::Completed));
-        assert!(GroupStatus::Active.can_transition_to(&GroupStatus::Cancelled));
-        assert!(!GroupStatus::Active.can_transition_to(&GroupStatus::Pending));
-
-        // Test valid transitions from Paused
-        assert!(GroupStatus::Paused.can_transition_to(&GroupStatus::Active));
-        assert!(GroupStatus::Paused.can_transition_to(&GroupStatus::Cancelled));
-        assert!(!GroupStatus::Paused.can_transition_to(&GroupStatus::Pending));
-        assert!(!GroupStatus::Paused.can_transition_to(&GroupStatus::Completed));
-
-        // Test terminal states cannot transition
-        assert!(!GroupStatus::Completed.can_transition_to(&GroupStatus::Active));
-        assert!(!GroupStatus::Completed.can_transition_to(&GroupStatus::Pending));
-        assert!(!GroupStatus::Completed.can_transition_to(&GroupStatus::Paused));
-        assert!(!GroupStatus::Completed.can_transition_to(&GroupStatus::Cancelled));
-
-        assert!(!GroupStatus::Cancelled.can_transition_to(&GroupStatus::Active));
-        assert!(!GroupStatus::Cancelled.can_transition_to(&GroupStatus::Pending));
-        assert!(!GroupStatus::Cancelled.can_transition_to(&GroupStatus::Paused));
-        assert!(!GroupStatus::Cancelled.can_transition_to(&GroupStatus::Completed));
-
-        // Test same state transitions are always valid
-        assert!(GroupStatus::Pending.can_transition_to(&GroupStatus::Pending));
-        assert!(GroupStatus::Active.can_transition_to(&GroupStatus::Active));
-        assert!(GroupStatus::Paused.can_transition_to(&GroupStatus::Paused));
-        assert!(GroupStatus::Completed.can_transition_to(&GroupStatus::Completed));
-        assert!(GroupStatus::Cancelled.can_transition_to(&GroupStatus::Cancelled));
-    }
-
-    #[test]
-    fn test_group_status_accepts_contributions() {
-        assert!(!GroupStatus::Pending.accepts_contributions());
-        assert!(GroupStatus::Active.accepts_contributions());
-        assert!(!GroupStatus::Paused.accepts_contributions());
-        assert!(!GroupStatus::Completed.accepts_contributions());
-        assert!(!GroupStatus::Cancelled.accepts_contributions());
+    fn test_collateral_amount() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        
+        let mut group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 3, 1234567890);
+        assert_eq!(group.collateral_amount(), Some(0));
+        
+        group.collateral_multiplier = 2;
+        assert_eq!(group.collateral_amount(), Some(20_000_000));
+        
+        group.contribution_amount = i128::MAX;
+        assert_eq!(group.collateral_amount(), None);
     }
 
     #[test]
-    fn test_group_status_can_process_payouts() {
-        assert!(!GroupStatus::Pending.can_process_payouts());
-        assert!(GroupStatus::Active.can_process_payouts());
-        assert!(!GroupStatus::Paused.can_process_payouts());
-        assert!(!GroupStatus::Completed.can_process_payouts());
-        assert!(!GroupStatus::Cancelled.can_process_payouts());
+    fn test_total_pool_amount() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        
+        let group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 5, 1234567890);
+        
+        assert_eq!(group.total_pool_amount(), 50_000_000); // 5 XLM total
     }
 
     #[test]
-    fn test_group_status_is_terminal() {
-        assert!(!GroupStatus::Pending.is_terminal());
-        assert!(!GroupStatus::Active.is_terminal());
-        assert!(!GroupStatus::Paused.is_terminal());
-        assert!(GroupStatus::Completed.is_terminal());
-        assert!(GroupStatus::Cancelled.is_terminal());
+    fn test_validate() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        
+        let group = Group::new(1, creator, Address::generate(&env), 10_000_000, 604800, 5, 1234567890);
+        assert!(group.validate());
     }
 }
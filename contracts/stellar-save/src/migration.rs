@@ -0,0 +1,422 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use crate::{
+    assignment,
+    contribution::ContributionRecord,
+    error::StellarSaveError,
+    group::{Group, LatePenalty},
+    status::GroupStatus,
+    storage::StorageKeyBuilder,
+    ttl, ContractConfig, MemberProfile,
+};
+
+/// Storage schema version written by this build of the contract.
+///
+/// Bump this whenever the layout of a stored struct (`Group`, `MemberProfile`,
+/// `ContributionRecord`, `PayoutRecord`, ...) changes, and add the matching
+/// step to `migrate_step` that rewrites the stored values into the new layout.
+pub const CURRENT_VERSION: u32 = 1;
+
+/// Returns the storage schema version recorded on-chain.
+///
+/// Deployments that predate version tracking have no version stored and
+/// report `0`.
+pub fn stored_version(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::contract_version())
+        .unwrap_or(0)
+}
+
+/// Records `version` as the current storage schema version.
+pub fn set_version(env: &Env, version: u32) {
+    env.storage()
+        .persistent()
+        .set(&StorageKeyBuilder::contract_version(), &version);
+}
+
+/// `ContractConfig` as stored by unversioned deployments, which kept the
+/// admin in the config and had no token allowlist.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContractConfigV0 {
+    pub admin: Address,
+    pub min_contribution: i128,
+    pub max_contribution: i128,
+    pub min_members: u32,
+    pub max_members: u32,
+    pub min_cycle_duration: u64,
+    pub max_cycle_duration: u64,
+}
+
+/// `Group` as stored by unversioned deployments.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct GroupV0 {
+    pub id: u64,
+    pub creator: Address,
+    pub contribution_amount: i128,
+    pub cycle_duration: u64,
+    pub max_members: u32,
+    pub min_members: u32,
+    pub member_count: u32,
+    pub current_cycle: u32,
+    pub is_active: bool,
+    pub status: GroupStatus,
+    pub created_at: u64,
+    pub started: bool,
+    pub started_at: u64,
+}
+
+/// `ContributionRecord` as stored by unversioned deployments.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ContributionRecordV0 {
+    pub member_address: Address,
+    pub group_id: u64,
+    pub cycle_number: u32,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// Group keys only unversioned deployments wrote.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum GroupKeyV0 {
+    /// Group status, kept apart from `Group` before version 1
+    Status(u64),
+}
+
+/// Storage keys only unversioned deployments wrote; they encode like the
+/// matching `StorageKey` variants did.
+#[contracttype(export = false)]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum StorageKeyV0 {
+    Group(GroupKeyV0),
+}
+
+/// Returns the admin of an unversioned deployment, which kept it in the
+/// config rather than under its own key.
+pub fn legacy_admin(env: &Env) -> Option<Address> {
+    if stored_version(env) != 0 || env.storage().persistent().has(&StorageKeyBuilder::contract_admin()) {
+        return None;
+    }
+    env.storage()
+        .persistent()
+        .get::<_, ContractConfigV0>(&StorageKeyBuilder::contract_config())
+        .map(|config| config.admin)
+}
+
+/// Migrates stored data from `from_version` up to `CURRENT_VERSION`.
+///
+/// Steps run one version at a time, and the stored version is updated after
+/// each step so a migration that runs out of budget can be resumed.
+/// `legacy_token` is only used when migrating from version 0 (see
+/// `migrate_step`).
+///
+/// # Returns
+/// The version the storage was migrated to.
+///
+/// # Errors
+/// * `InvalidState` - If `from_version` does not match the stored version,
+///   the storage is already at `CURRENT_VERSION`, or a step cannot migrate
+///   the stored data (see `migrate_step`)
+pub fn migrate(env: &Env, from_version: u32, legacy_token: Option<Address>) -> Result<u32, StellarSaveError> {
+    if from_version != stored_version(env) || from_version >= CURRENT_VERSION {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    let mut version = from_version;
+    while version < CURRENT_VERSION {
+        migrate_step(env, version, &legacy_token)?;
+        version += 1;
+        set_version(env, version);
+    }
+
+    Ok(version)
+}
+
+/// Rewrites storage written by `version` into the layout of `version + 1`.
+///
+/// Version 0 kept the admin inside `ContractConfig`, the group status under
+/// its own key, and had no token, late policy, collateral or pause fields on
+/// `Group` nor late, penalty or credit fields on `ContributionRecord`. The
+/// step moves the admin to its own key, and rewrites the config and every
+/// group up to `next_group_id` with its contributions into the current
+/// layout: no grace period, penalty, collateral or pause time, and member
+/// positions re-indexed. v0 groups were all denominated in one token, which
+/// the contract cannot infer, so it is `legacy_token`; it also becomes the
+/// only allowed token. Fails with `InvalidState` if groups exist and no
+/// `legacy_token` is given.
+///
+/// All groups are rewritten in one call, so a deployment with more groups
+/// than a transaction's budget allows cannot be migrated in place.
+fn migrate_step(env: &Env, version: u32, legacy_token: &Option<Address>) -> Result<(), StellarSaveError> {
+    match version {
+        0 => {
+            let storage = env.storage().persistent();
+            let groups_created: u64 = storage
+                .get(&StorageKeyBuilder::next_group_id())
+                .unwrap_or(0);
+            if groups_created > 0 && legacy_token.is_none() {
+                return Err(StellarSaveError::InvalidState);
+            }
+
+            let config_key = StorageKeyBuilder::contract_config();
+            if let Some(admin) = legacy_admin(env) {
+                let config: ContractConfigV0 = storage.get(&config_key).unwrap();
+                let mut allowed_tokens = Vec::new(env);
+                if let Some(token) = legacy_token {
+                    allowed_tokens.push_back(token.clone());
+                }
+                storage.set(&StorageKeyBuilder::contract_admin(), &admin);
+                storage.set(&config_key, &ContractConfig {
+                    allowed_tokens,
+                    min_contribution: config.min_contribution,
+                    max_contribution: config.max_contribution,
+                    min_members: config.min_members,
+                    max_members: config.max_members,
+                    min_cycle_duration: config.min_cycle_duration,
+                    max_cycle_duration: config.max_cycle_duration,
+                });
+                ttl::extend_contract(env);
+            }
+
+            if let Some(token) = legacy_token {
+                for group_id in 1..=groups_created {
+                    migrate_group_v0(env, group_id, token);
+                }
+            }
+            Ok(())
+        }
+        _ => Err(StellarSaveError::InvalidState),
+    }
+}
+
+/// Rewrites one unversioned group and its contributions, if it exists.
+fn migrate_group_v0(env: &Env, group_id: u64, token: &Address) {
+    let storage = env.storage().persistent();
+    let Some(old) = storage.get::<_, GroupV0>(&StorageKeyBuilder::group_data(group_id)) else {
+        return;
+    };
+
+    // The separate status entry was the authoritative one
+    let status_key = StorageKeyV0::Group(GroupKeyV0::Status(group_id));
+    let status = storage.get(&status_key).unwrap_or(old.status);
+    storage.remove(&status_key);
+
+    let group = Group {
+        id: old.id,
+        creator: old.creator,
+        token: token.clone(),
+        contribution_amount: old.contribution_amount,
+        cycle_duration: old.cycle_duration,
+        max_members: old.max_members,
+        min_members: old.min_members,
+        member_count: old.member_count,
+        current_cycle: old.current_cycle,
+        is_active: old.is_active,
+        status,
+        created_at: old.created_at,
+        started: old.started,
+        grace_period: 0,
+        late_penalty: LatePenalty::None,
+        collateral_multiplier: 0,
+        started_at: old.started_at,
+        paused_at: 0,
+        total_paused: 0,
+    };
+    storage.set(&StorageKeyBuilder::group_data(group_id), &group);
+
+    let members: Vec<Address> = storage
+        .get(&StorageKeyBuilder::group_members(group_id))
+        .unwrap_or(Vec::new(env));
+    for member in members.iter() {
+        // Profiles kept their layout; re-setting the position builds the index
+        if let Some(mut profile) = storage
+            .get::<_, MemberProfile>(&StorageKeyBuilder::member_profile(group_id, member.clone()))
+        {
+            let position = profile.payout_position;
+            assignment::set_position(env, &mut profile, position);
+        }
+
+        for cycle in 0..=group.current_cycle {
+            let key = StorageKeyBuilder::contribution_individual(group_id, cycle, member.clone());
+            if let Some(old) = storage.get::<_, ContributionRecordV0>(&key) {
+                storage.set(&key, &ContributionRecord::new(
+                    old.member_address,
+                    old.group_id,
+                    old.cycle_number,
+                    old.amount,
+                    old.timestamp,
+                ));
+            }
+        }
+    }
+
+    ttl::extend_all(env, &group);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StellarSaveContract;
+    use soroban_sdk::testutils::Address as _;
+
+    /// Registers the contract and strips the keys unversioned deployments
+    /// did not write.
+    fn register_unversioned(env: &Env) -> Address {
+        let contract_id = env.register(StellarSaveContract, (Address::generate(env),));
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().remove(&StorageKeyBuilder::contract_version());
+            env.storage().persistent().remove(&StorageKeyBuilder::contract_admin());
+        });
+        contract_id
+    }
+
+    fn config_v0(admin: &Address) -> ContractConfigV0 {
+        ContractConfigV0 {
+            admin: admin.clone(),
+            min_contribution: 1,
+            max_contribution: 1_000_000,
+            min_members: 2,
+            max_members: 10,
+            min_cycle_duration: 3600,
+            max_cycle_duration: 604800,
+        }
+    }
+
+    fn group_v0(env: &Env, member_count: u32) -> GroupV0 {
+        GroupV0 {
+            id: 1,
+            creator: Address::generate(env),
+            contribution_amount: 100,
+            cycle_duration: 3600,
+            max_members: 5,
+            min_members: 2,
+            member_count,
+            current_cycle: 0,
+            is_active: false,
+            status: GroupStatus::Pending,
+            created_at: 12345,
+            started: false,
+            started_at: 0,
+        }
+    }
+
+    #[test]
+    fn test_migrate_from_unversioned() {
+        let env = Env::default();
+        let contract_id = register_unversioned(&env);
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(&StorageKeyBuilder::contract_config(), &config_v0(&admin));
+
+            assert_eq!(stored_version(&env), 0);
+            assert_eq!(legacy_admin(&env), Some(admin.clone()));
+            assert_eq!(migrate(&env, 0, None), Ok(CURRENT_VERSION));
+            assert_eq!(stored_version(&env), CURRENT_VERSION);
+
+            let storage = env.storage().persistent();
+            assert_eq!(storage.get(&StorageKeyBuilder::contract_admin()), Some(admin.clone()));
+            let config: ContractConfig = storage.get(&StorageKeyBuilder::contract_config()).unwrap();
+            assert_eq!(config.allowed_tokens.len(), 0);
+            assert_eq!(config.max_members, 10);
+            assert_eq!(legacy_admin(&env), None);
+        });
+    }
+
+    #[test]
+    fn test_migrate_rewrites_unversioned_groups() {
+        let env = Env::default();
+        let contract_id = register_unversioned(&env);
+        let admin = Address::generate(&env);
+        let token = Address::generate(&env);
+        let member1 = Address::generate(&env);
+        let member2 = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            storage.set(&StorageKeyBuilder::contract_config(), &config_v0(&admin));
+            storage.set(&StorageKeyBuilder::next_group_id(), &1u64);
+            storage.set(&StorageKeyBuilder::group_data(1), &group_v0(&env, 2));
+            // The separate status entry had moved on from the copy in the group
+            storage.set(&StorageKeyV0::Group(GroupKeyV0::Status(1)), &GroupStatus::Active);
+
+            let mut members = Vec::new(&env);
+            for (position, member) in [&member1, &member2].into_iter().enumerate() {
+                members.push_back(member.clone());
+                storage.set(
+                    &StorageKeyBuilder::member_profile(1, member.clone()),
+                    &MemberProfile {
+                        address: member.clone(),
+                        group_id: 1,
+                        payout_position: position as u32,
+                        joined_at: 12345,
+                    },
+                );
+            }
+            storage.set(&StorageKeyBuilder::group_members(1), &members);
+            storage.set(
+                &StorageKeyBuilder::contribution_individual(1, 0, member1.clone()),
+                &ContributionRecordV0 {
+                    member_address: member1.clone(),
+                    group_id: 1,
+                    cycle_number: 0,
+                    amount: 100,
+                    timestamp: 20000,
+                },
+            );
+
+            assert_eq!(migrate(&env, 0, Some(token.clone())), Ok(CURRENT_VERSION));
+
+            let group: Group = storage.get(&StorageKeyBuilder::group_data(1)).unwrap();
+            assert_eq!(group.token, token);
+            assert_eq!(group.status, GroupStatus::Active);
+            assert_eq!(group.member_count, 2);
+            assert_eq!(group.late_penalty, LatePenalty::None);
+            assert_eq!(group.total_paused, 0);
+            assert!(!storage.has(&StorageKeyV0::Group(GroupKeyV0::Status(1))));
+
+            let config: ContractConfig = storage.get(&StorageKeyBuilder::contract_config()).unwrap();
+            assert_eq!(config.allowed_tokens, Vec::from_array(&env, [token.clone()]));
+
+            assert_eq!(assignment::position_holder(&env, 1, 0), Some(member1.clone()));
+            assert_eq!(assignment::position_holder(&env, 1, 1), Some(member2.clone()));
+
+            let record: ContributionRecord = storage
+                .get(&StorageKeyBuilder::contribution_individual(1, 0, member1.clone()))
+                .unwrap();
+            assert_eq!(record.amount, 100);
+            assert_eq!(record.timestamp, 20000);
+            assert!(!record.is_late);
+            assert_eq!(record.total_paid(), 100);
+        });
+    }
+
+    #[test]
+    fn test_migrate_unversioned_groups_needs_token() {
+        let env = Env::default();
+        let contract_id = register_unversioned(&env);
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            let storage = env.storage().persistent();
+            storage.set(&StorageKeyBuilder::contract_config(), &config_v0(&admin));
+            storage.set(&StorageKeyBuilder::next_group_id(), &1u64);
+            storage.set(&StorageKeyBuilder::group_data(1), &group_v0(&env, 0));
+
+            assert_eq!(migrate(&env, 0, None), Err(StellarSaveError::InvalidState));
+            assert_eq!(stored_version(&env), 0);
+            assert_eq!(legacy_admin(&env), Some(admin.clone()));
+        });
+    }
+
+    #[test]
+    fn test_migrate_rejects_wrong_or_current_version() {
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        env.as_contract(&contract_id, || {
+            assert_eq!(migrate(&env, 1, None), Err(StellarSaveError::InvalidState));
+            set_version(&env, CURRENT_VERSION);
+            assert_eq!(migrate(&env, CURRENT_VERSION, None), Err(StellarSaveError::InvalidState));
+        });
+    }
+}
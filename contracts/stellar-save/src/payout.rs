@@ -1,4 +1,5 @@
 ﻿use soroban_sdk::{contracttype, Address};
+use crate::error::StellarSaveError;
 
 /// Payout Record structure for tracking payout events in rotational savings groups.
 /// 
@@ -93,6 +94,16 @@ impl PayoutRecord {
         self.group_id == group_id
     }
 
+    /// Returns the payout amount in whole token units for a token with the
+    /// given number of decimals (see `get_token_decimals`).
+    /// Note: This is a helper for display purposes; actual amount is in the
+    /// token's smallest unit. Returns `Overflow` if `10^decimals` does not
+    /// fit in an `i128`.
+    pub fn amount_in_units(&self, decimals: u32) -> Result<i128, StellarSaveError> {
+        let scale = 10i128.checked_pow(decimals).ok_or(StellarSaveError::Overflow)?;
+        Ok(self.amount / scale)
+    }
+
     /// Returns the payout amount in XLM (converted from stroops).
     /// Note: This is a helper for display purposes; actual amount is in stroops.
     pub fn amount_in_xlm(&self) -> i128 {
@@ -221,6 +232,24 @@ mod tests {
         assert_eq!(payout.amount_in_xlm(), 5);
     }
 
+    #[test]
+    fn test_amount_in_units() {
+        let env = Env::default();
+        let recipient = Address::generate(&env);
+        
+        let payout = PayoutRecord::new(
+            recipient,
+            1,
+            0,
+            250_000_000, // 250 units of a 6-decimal token (e.g. USDC)
+            1234567890,
+        );
+
+        assert_eq!(payout.amount_in_units(6), Ok(250));
+        assert_eq!(payout.amount_in_units(7), Ok(25));
+        assert_eq!(payout.amount_in_units(39), Err(StellarSaveError::Overflow));
+    }
+
     #[test]
     fn test_multiple_payouts_same_group() {
         let env = Env::default();
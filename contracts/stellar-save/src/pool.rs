@@ -11,7 +11,7 @@ use crate::storage::StorageKeyBuilder;
 /// - Pool return amount calculations
 /// 
 /// The pool represents the total funds available for distribution in a cycle,
-/// calculated as: pool_amount = contribution_amount × member_count
+/// calculated as: pool_amount = contribution_amount × (member_count - defaulted_count)
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct PoolInfo {
@@ -24,10 +24,13 @@ pub struct PoolInfo {
     /// Total number of members in the group
     pub member_count: u32,
     
+    /// Number of defaulted members in this cycle not covered by collateral
+    pub defaulted_count: u32,
+    
     /// Fixed contribution amount per member in stroops
     pub contribution_amount: i128,
     
-    /// Total pool amount (contribution_amount × member_count)
+    /// Total pool amount (contribution_amount × expected contributors)
     pub total_pool_amount: i128,
     
     /// Total amount contributed so far in this cycle
@@ -36,7 +39,7 @@ pub struct PoolInfo {
     /// Number of members who have contributed in this cycle
     pub contributors_count: u32,
     
-    /// Whether the cycle is complete (all members have contributed)
+    /// Whether the cycle is complete (all non-defaulted members have contributed)
     pub is_cycle_complete: bool,
 }
 
@@ -49,26 +52,28 @@ impl PoolInfo {
         self.total_pool_amount
     }
     
-    /// Checks if all members have contributed to complete the cycle.
+    /// Number of members expected to contribute, excluding defaulted members.
+    pub fn expected_contributors(&self) -> u32 {
+        self.member_count.saturating_sub(self.defaulted_count)
+    }
+    
+    /// Checks if all non-defaulted members have contributed to complete the cycle.
     pub fn is_complete(&self) -> bool {
-        self.contributors_count >= self.member_count
+        self.contributors_count >= self.expected_contributors()
     }
     
     /// Calculates remaining contributions needed to complete the cycle.
     pub fn remaining_contributions_needed(&self) -> u32 {
-        if self.contributors_count >= self.member_count {
-            0
-        } else {
-            self.member_count - self.contributors_count
-        }
+        self.expected_contributors().saturating_sub(self.contributors_count)
     }
     
     /// Calculates the percentage of cycle completion (0-100).
     pub fn completion_percentage(&self) -> u32 {
-        if self.member_count == 0 {
+        let expected = self.expected_contributors();
+        if expected == 0 {
             return 0;
         }
-        ((self.contributors_count as u64 * 100) / self.member_count as u64) as u32
+        ((self.contributors_count as u64 * 100) / expected as u64) as u32
     }
 }
 
@@ -206,6 +211,31 @@ impl PoolCalculator {
         Ok(count)
     }
     
+    /// Retrieves the number of defaulted members not covered by collateral for a cycle.
+    /// 
+    /// # Arguments
+    /// * `env` - Soroban environment
+    /// * `group_id` - ID of the group
+    /// * `cycle` - Cycle number
+    /// 
+    /// # Returns
+    /// * `Ok(count)` - The number of defaulted members (0 if none were marked)
+    pub fn get_cycle_default_count(
+        env: &Env,
+        group_id: u64,
+        cycle: u32,
+    ) -> Result<u32, StellarSaveError> {
+        let defaults_key = StorageKeyBuilder::contribution_cycle_defaults(group_id, cycle);
+        
+        let count: u32 = env
+            .storage()
+            .persistent()
+            .get(&defaults_key)
+            .unwrap_or(0);
+        
+        Ok(count)
+    }
+    
     /// Builds complete pool information for a group and cycle.
     /// 
     /// This is the primary function for getting comprehensive pool data.
@@ -230,8 +260,16 @@ impl PoolCalculator {
         // Get contribution amount
         let contribution_amount = Self::get_contribution_amount(env, group_id)?;
         
+        // Defaulted members are excluded from the expected pool
+        let defaulted_count = Self::get_cycle_default_count(env, group_id, cycle)?;
+        let expected_contributors = member_count.saturating_sub(defaulted_count);
+        
         // Calculate total pool
-        let total_pool_amount = Self::calculate_total_pool(contribution_amount, member_count)?;
+        let total_pool_amount = if expected_contributors == 0 {
+            0
+        } else {
+            Self::calculate_total_pool(contribution_amount, expected_contributors)?
+        };
         
         // Get current cycle contributions
         let current_contributions = Self::get_cycle_contributions_total(env, group_id, cycle)?;
@@ -240,12 +278,13 @@ impl PoolCalculator {
         let contributors_count = Self::get_cycle_contributor_count(env, group_id, cycle)?;
         
         // Determine if cycle is complete
-        let is_cycle_complete = contributors_count >= member_count;
+        let is_cycle_complete = contributors_count >= expected_contributors;
         
         Ok(PoolInfo {
             group_id,
             cycle,
             member_count,
+            defaulted_count,
             contribution_amount,
             total_pool_amount,
             current_contributions,
@@ -257,7 +296,7 @@ impl PoolCalculator {
     /// Validates that a pool is ready for payout.
     /// 
     /// A pool is ready when:
-    /// - All members have contributed
+    /// - All non-defaulted members have contributed
     /// - Total contributions equal the expected pool amount
     /// 
     /// # Arguments
@@ -267,7 +306,7 @@ impl PoolCalculator {
     /// * `Ok(())` if pool is ready for payout
     /// * `Err(StellarSaveError)` if pool is not ready
     pub fn validate_pool_ready_for_payout(pool_info: &PoolInfo) -> Result<(), StellarSaveError> {
-        // Check if all members have contributed
+        // Check if all non-defaulted members have contributed
         if !pool_info.is_cycle_complete {
             return Err(StellarSaveError::CycleNotComplete);
         }
@@ -368,6 +407,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
@@ -384,6 +424,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
@@ -400,6 +441,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 3_000_000i128,
@@ -416,6 +458,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 3_000_000i128,
@@ -432,6 +475,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
@@ -448,6 +492,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 0i128,
@@ -464,6 +509,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 10,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 10_000_000i128,
             current_contributions: 5_000_000i128,
@@ -480,6 +526,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
@@ -496,6 +543,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 3,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 3_000_000i128,
             current_contributions: 1_000_000i128,
@@ -513,6 +561,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
@@ -530,6 +579,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 3_000_000i128,
@@ -548,6 +598,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 4_500_000i128, // Mismatch!
@@ -566,6 +617,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
@@ -583,6 +635,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
@@ -594,6 +647,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
@@ -610,6 +664,7 @@ mod tests {
             group_id: 1,
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
@@ -621,6 +676,7 @@ mod tests {
             group_id: 2, // Different group
             cycle: 0,
             member_count: 5,
+            defaulted_count: 0,
             contribution_amount: 1_000_000i128,
             total_pool_amount: 5_000_000i128,
             current_contributions: 5_000_000i128,
@@ -646,4 +702,24 @@ mod tests {
             assert_eq!(result.unwrap(), *expected);
         }
     }
+
+    #[test]
+    fn test_pool_info_with_defaults() {
+        let pool = PoolInfo {
+            group_id: 1,
+            cycle: 0,
+            member_count: 5,
+            defaulted_count: 2,
+            contribution_amount: 1_000_000i128,
+            total_pool_amount: 3_000_000i128,
+            current_contributions: 2_000_000i128,
+            contributors_count: 2,
+            is_cycle_complete: false,
+        };
+        
+        assert_eq!(pool.expected_contributors(), 3);
+        assert_eq!(pool.remaining_contributions_needed(), 1);
+        assert_eq!(pool.completion_percentage(), 66);
+        assert!(!pool.is_complete());
+    }
 }
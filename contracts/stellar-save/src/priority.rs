@@ -0,0 +1,187 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use crate::{
+    assignment,
+    error::StellarSaveError,
+    events::EventEmitter,
+    group::Group,
+    storage::StorageKeyBuilder,
+    ttl, AssignmentMode, MemberProfile, StellarSaveContract,
+};
+
+/// Outcome of a hardship priority request.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PriorityStatus {
+    /// Votes are still being collected
+    Open,
+    /// A majority approved; the requester was moved to the current cycle
+    Approved,
+    /// Enough members rejected that approval is no longer possible
+    Rejected,
+}
+
+/// A member's request to be paid in the current cycle of a `Vote` group.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PriorityRequest {
+    /// Member asking to be paid early
+    pub requester: Address,
+
+    /// Cycle the requester asks to be paid in
+    pub cycle: u32,
+
+    /// Members who approved the request
+    pub approvals: Vec<Address>,
+
+    /// Members who rejected the request
+    pub rejections: Vec<Address>,
+
+    /// Current outcome of the request
+    pub status: PriorityStatus,
+
+    /// Timestamp the request was filed
+    pub requested_at: u64,
+}
+
+/// Returns true if the group's payout positions were assigned in `Vote` mode.
+pub fn is_vote(env: &Env, group_id: u64) -> bool {
+    assignment::assignment_mode(env, group_id) == Some(AssignmentMode::Vote)
+}
+
+/// Returns the group's latest priority request, if any.
+pub fn get_request(env: &Env, group_id: u64) -> Option<PriorityRequest> {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_priority_request(group_id))
+}
+
+/// Files a hardship request for `member` to be paid in the group's current cycle.
+///
+/// One request can be filed per cycle. The requester must not have been
+/// paid and must not already be scheduled for the current cycle.
+///
+/// # Errors
+/// * `InvalidState` - Not a vote group, not active, the cycle was already
+///   paid out or already has a request, or the member is not eligible
+/// * `NotMember` - The requester is not a member
+pub fn request(env: &Env, group: &Group, member: &Address) -> Result<(), StellarSaveError> {
+    let group_id = group.id;
+    let cycle = group.current_cycle;
+    if !is_vote(env, group_id) || !group.status.can_process_payouts() || payout_processed(env, group_id, cycle) {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    if let Some(existing) = get_request(env, group_id) {
+        if existing.cycle == cycle {
+            return Err(StellarSaveError::InvalidState);
+        }
+    }
+
+    let profile: MemberProfile = env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::member_profile(group_id, member.clone()))
+        .ok_or(StellarSaveError::NotMember)?;
+    if profile.payout_position <= cycle {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    let request = PriorityRequest {
+        requester: member.clone(),
+        cycle,
+        approvals: Vec::new(env),
+        rejections: Vec::new(env),
+        status: PriorityStatus::Open,
+        requested_at: env.ledger().timestamp(),
+    };
+    env.storage()
+        .persistent()
+        .set(&StorageKeyBuilder::group_priority_request(group_id), &request);
+    EventEmitter::emit_priority_requested(env, group_id, member.clone(), cycle, request.requested_at);
+    Ok(())
+}
+
+/// Records `voter`'s approval or rejection of the group's open request.
+///
+/// Every member except the requester may vote once. A strict majority of
+/// them approving swaps the requester's payout position with the member
+/// scheduled for the current cycle; once approval can no longer reach a
+/// majority the request is rejected.
+///
+/// # Returns
+/// The status of the request after the vote.
+///
+/// # Errors
+/// * `InvalidState` - No open request for the current cycle, the cycle was
+///   already paid out, or the voter already voted
+/// * `NotMember` - The voter is not a member
+/// * `Unauthorized` - The requester voted on their own request
+pub fn vote(
+    env: &Env,
+    group: &Group,
+    voter: &Address,
+    approve: bool,
+) -> Result<PriorityStatus, StellarSaveError> {
+    let group_id = group.id;
+    let cycle = group.current_cycle;
+    let mut request = get_request(env, group_id).ok_or(StellarSaveError::InvalidState)?;
+    if request.status != PriorityStatus::Open
+        || request.cycle != cycle
+        || !group.status.can_process_payouts()
+        || payout_processed(env, group_id, cycle)
+    {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    if !env.storage()
+        .persistent()
+        .has(&StorageKeyBuilder::member_profile(group_id, voter.clone()))
+    {
+        return Err(StellarSaveError::NotMember);
+    }
+    if *voter == request.requester {
+        return Err(StellarSaveError::Unauthorized);
+    }
+    if request.approvals.contains(voter) || request.rejections.contains(voter) {
+        return Err(StellarSaveError::InvalidState);
+    }
+
+    if approve {
+        request.approvals.push_back(voter.clone());
+    } else {
+        request.rejections.push_back(voter.clone());
+    }
+
+    let voters = group.member_count.saturating_sub(1);
+    if request.approvals.len() * 2 > voters {
+        let scheduled = StellarSaveContract::find_recipient(env, group_id, cycle)?;
+        assignment::swap_positions(env, group_id, &request.requester, &scheduled)?;
+        ttl::extend_member(env, group, &request.requester);
+        ttl::extend_member(env, group, &scheduled);
+        request.status = PriorityStatus::Approved;
+    } else if request.rejections.len() * 2 >= voters {
+        request.status = PriorityStatus::Rejected;
+    }
+
+    env.storage()
+        .persistent()
+        .set(&StorageKeyBuilder::group_priority_request(group_id), &request);
+    if request.status != PriorityStatus::Open {
+        EventEmitter::emit_priority_resolved(
+            env,
+            group_id,
+            request.requester.clone(),
+            cycle,
+            request.status == PriorityStatus::Approved,
+            env.ledger().timestamp(),
+        );
+    }
+    Ok(request.status)
+}
+
+/// Returns true if the cycle's payout has already been executed.
+fn payout_processed(env: &Env, group_id: u64, cycle: u32) -> bool {
+    env.storage()
+        .persistent()
+        .get::<_, bool>(&StorageKeyBuilder::payout_status(group_id, cycle))
+        .unwrap_or(false)
+}
@@ -0,0 +1,180 @@
+use soroban_sdk::{contracttype, Address, Env, Vec};
+use crate::{
+    error::StellarSaveError,
+    storage::{StorageKey, StorageKeyBuilder},
+    ttl,
+};
+
+/// Value held and distributed by the contract for one token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenStats {
+    /// Token contract address
+    pub token: Address,
+
+    /// Amount of the token currently held by the contract (contributions,
+    /// collateral, reserves and unclaimed refunds)
+    pub total_value_locked: i128,
+
+    /// Amount of the token paid out to cycle recipients so far
+    pub total_paid_out: i128,
+}
+
+/// Platform-wide statistics returned by `get_platform_stats`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PlatformStats {
+    /// Groups that exist (created and not deleted)
+    pub total_groups: u64,
+
+    /// Groups whose rotation is running (Active or Paused)
+    pub active_groups: u64,
+
+    /// Memberships across all groups
+    pub total_members: u64,
+
+    /// Per-token value locked and paid out
+    pub tokens: Vec<TokenStats>,
+}
+
+/// Reads a platform counter, defaulting to 0.
+pub fn get_counter(env: &Env, key: &StorageKey) -> u64 {
+    env.storage().persistent().get(key).unwrap_or(0)
+}
+
+/// Adds one to a platform counter.
+pub fn increment(env: &Env, key: &StorageKey) -> Result<(), StellarSaveError> {
+    let value = get_counter(env, key)
+        .checked_add(1)
+        .ok_or(StellarSaveError::Overflow)?;
+    env.storage().persistent().set(key, &value);
+    ttl::extend_global(env, key);
+    Ok(())
+}
+
+/// Subtracts one from a platform counter, stopping at 0.
+pub fn decrement(env: &Env, key: &StorageKey) {
+    let value = get_counter(env, key).saturating_sub(1);
+    env.storage().persistent().set(key, &value);
+    ttl::extend_global(env, key);
+}
+
+/// Returns the stats recorded for `token`, or zeros if it was never used.
+pub fn get_token_stats(env: &Env, token: &Address) -> TokenStats {
+    env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::token_stats(token.clone()))
+        .unwrap_or(TokenStats {
+            token: token.clone(),
+            total_value_locked: 0,
+            total_paid_out: 0,
+        })
+}
+
+/// Applies `locked_delta` to the token's value locked and adds `paid_out`
+/// to its total paid out, registering the token on first use.
+fn update_token_stats(
+    env: &Env,
+    token: &Address,
+    locked_delta: i128,
+    paid_out: i128,
+) -> Result<(), StellarSaveError> {
+    let key = StorageKeyBuilder::token_stats(token.clone());
+    if !env.storage().persistent().has(&key) {
+        let tokens_key = StorageKeyBuilder::stats_tokens();
+        let mut tokens: Vec<Address> = env.storage()
+            .persistent()
+            .get(&tokens_key)
+            .unwrap_or(Vec::new(env));
+        tokens.push_back(token.clone());
+        env.storage().persistent().set(&tokens_key, &tokens);
+        ttl::extend_global(env, &tokens_key);
+    }
+
+    let mut stats = get_token_stats(env, token);
+    stats.total_value_locked = stats.total_value_locked
+        .checked_add(locked_delta)
+        .ok_or(StellarSaveError::Overflow)?;
+    stats.total_paid_out = stats.total_paid_out
+        .checked_add(paid_out)
+        .ok_or(StellarSaveError::Overflow)?;
+    env.storage().persistent().set(&key, &stats);
+    ttl::extend_global(env, &key);
+    Ok(())
+}
+
+/// Records `amount` of `token` transferred into the contract.
+pub fn record_deposit(env: &Env, token: &Address, amount: i128) -> Result<(), StellarSaveError> {
+    update_token_stats(env, token, amount, 0)
+}
+
+/// Records `amount` of `token` returned to a member (refunds, collateral).
+pub fn record_withdrawal(env: &Env, token: &Address, amount: i128) -> Result<(), StellarSaveError> {
+    update_token_stats(env, token, -amount, 0)
+}
+
+/// Records a cycle payout of `amount` of `token`.
+pub fn record_payout(env: &Env, token: &Address, amount: i128) -> Result<(), StellarSaveError> {
+    update_token_stats(env, token, -amount, amount)
+}
+
+/// Collects the platform counters and per-token stats.
+pub fn get_platform_stats(env: &Env) -> PlatformStats {
+    let tokens: Vec<Address> = env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::stats_tokens())
+        .unwrap_or(Vec::new(env));
+
+    let mut token_stats = Vec::new(env);
+    for token in tokens.iter() {
+        token_stats.push_back(get_token_stats(env, &token));
+    }
+
+    PlatformStats {
+        total_groups: get_counter(env, &StorageKeyBuilder::total_groups()),
+        active_groups: get_counter(env, &StorageKeyBuilder::active_groups()),
+        total_members: get_counter(env, &StorageKeyBuilder::total_members()),
+        tokens: token_stats,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StellarSaveContract;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_counters_never_go_negative() {
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        env.as_contract(&contract_id, || {
+            let key = StorageKeyBuilder::active_groups();
+            decrement(&env, &key);
+            assert_eq!(get_counter(&env, &key), 0);
+            increment(&env, &key).unwrap();
+            increment(&env, &key).unwrap();
+            decrement(&env, &key);
+            assert_eq!(get_counter(&env, &key), 1);
+        });
+    }
+
+    #[test]
+    fn test_token_stats_track_flows() {
+        let env = Env::default();
+        let contract_id = env.register(StellarSaveContract, (Address::generate(&env),));
+        let token = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            record_deposit(&env, &token, 300).unwrap();
+            record_payout(&env, &token, 200).unwrap();
+            record_withdrawal(&env, &token, 50).unwrap();
+
+            let stats = get_platform_stats(&env);
+            assert_eq!(stats.tokens.len(), 1);
+            assert_eq!(
+                stats.tokens.get(0).unwrap(),
+                TokenStats { token: token.clone(), total_value_locked: 50, total_paid_out: 200 }
+            );
+        });
+    }
+}
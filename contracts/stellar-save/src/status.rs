@@ -1,4 +1,10 @@
-﻿use soroban_sdk::{contracterror, contracttype};
+﻿use soroban_sdk::{contracterror, contracttype, Address, Env};
+use crate::error::StellarSaveError;
+use crate::events::EventEmitter;
+use crate::group::Group;
+use crate::storage::StorageKeyBuilder;
+use crate::stats;
+use crate::ttl;
 
 /// Error types for invalid state transitions.
 #[contracterror]
@@ -13,6 +19,13 @@ pub enum StatusError {
     AlreadyCancelled = 3,
 }
 
+impl From<StatusError> for StellarSaveError {
+    /// Every rejected transition surfaces to callers as `InvalidState`.
+    fn from(_: StatusError) -> Self {
+        StellarSaveError::InvalidState
+    }
+}
+
 
 /// GroupStatus enum representing the lifecycle states of a savings group.
 /// 
@@ -117,6 +130,11 @@ impl GroupStatus {
         matches!(self, GroupStatus::Completed | GroupStatus::Cancelled)
     }
     
+    /// Checks if the group's rotation is running (Active or Paused).
+    pub fn is_running(&self) -> bool {
+        matches!(self, GroupStatus::Active | GroupStatus::Paused)
+    }
+    
     /// Checks if the group can accept contributions in its current state.
     pub fn can_accept_contributions(&self) -> bool {
         matches!(self, GroupStatus::Active)
@@ -169,6 +187,12 @@ impl GroupStatus {
 }
 
 // Implement Display-like functionality through as_str
+impl core::fmt::Display for GroupStatus {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 impl GroupStatus {
     /// Returns a detailed description of the status.
     pub fn description(&self) -> &'static str {
@@ -182,6 +206,54 @@ impl GroupStatus {
     }
 }
 
+/// Moves a group to `new_status`, the single path for every status change.
+///
+/// Validates the move against the transition table, applies it to the group
+/// (see `Group::set_status`), persists the group, extends the group-level
+/// storage TTLs to the new remaining lifetime, keeps the platform's active
+/// group counter in step and emits `GroupStatusChanged` with the old and new
+/// status codes.
+///
+/// # Arguments
+/// * `env` - The Soroban environment
+/// * `group` - The group being transitioned
+/// * `new_status` - The target status
+/// * `actor` - The address responsible for the change (for event emission)
+///
+/// # Errors
+/// * `InvalidState` - If the transition table does not allow the move
+pub fn transition(
+    env: &Env,
+    group: &mut Group,
+    new_status: GroupStatus,
+    actor: &Address,
+) -> Result<(), StellarSaveError> {
+    let timestamp = env.ledger().timestamp();
+    let old_status = group.set_status(new_status, timestamp)?;
+
+    env.storage()
+        .persistent()
+        .set(&StorageKeyBuilder::group_data(group.id), group);
+    ttl::extend_group(env, group);
+
+    let active_key = StorageKeyBuilder::active_groups();
+    if !old_status.is_running() && new_status.is_running() {
+        stats::increment(env, &active_key)?;
+    } else if old_status.is_running() && !new_status.is_running() {
+        stats::decrement(env, &active_key);
+    }
+
+    EventEmitter::emit_group_status_changed(
+        env,
+        group.id,
+        old_status.to_u32(),
+        new_status.to_u32(),
+        actor.clone(),
+        timestamp,
+    );
+
+    Ok(())
+}
 
 #[cfg(test)]
 mod tests {
@@ -415,4 +487,13 @@ mod tests {
         // Can cancel from Paused
         assert!(GroupStatus::Paused.transition_to(GroupStatus::Cancelled).is_ok());
     }
+
+    #[test]
+    fn test_status_error_maps_to_invalid_state() {
+        let err: StellarSaveError = GroupStatus::Completed
+            .can_transition_to(GroupStatus::Active)
+            .unwrap_err()
+            .into();
+        assert_eq!(err, StellarSaveError::InvalidState);
+    }
 }
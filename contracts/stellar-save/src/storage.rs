@@ -47,9 +47,45 @@ pub enum GroupKey {
     /// Stores the list of member addresses for efficient member enumeration.
     Members(u64),
     
-    /// Group status: GROUP_STATUS_{id}
-    /// Stores the current GroupStatus for quick status checks.
-    Status(u64),
+    /// Group reserve: GROUP_RESERVE_{id}
+    /// Accumulated late penalties held by the group, outside the cycle pool.
+    Reserve(u64),
+    
+    /// Group cancel votes: GROUP_CANCEL_VOTES_{id}
+    /// Number of members who have voted to cancel an active group.
+    CancelVotes(u64),
+    
+    /// Group refund pool: GROUP_REFUND_POOL_{id}
+    /// Funds set aside for refunds after cancellation, reduced on each claim.
+    RefundPool(u64),
+    
+    /// Payout draw: GROUP_PAYOUT_DRAW_{id}
+    /// Seed and resulting order of a randomized payout assignment.
+    PayoutDraw(u64),
+    
+    /// Assignment mode: GROUP_ASSIGNMENT_MODE_{id}
+    /// Mode chosen at creation, then the mode the positions were assigned with.
+    AssignmentMode(u64),
+    
+    /// Commit deadline: GROUP_COMMIT_DEADLINE_{id}
+    /// Timestamp after which seed commitments close and reveals open.
+    CommitDeadline(u64),
+    
+    /// Reveal deadline: GROUP_REVEAL_DEADLINE_{id}
+    /// Timestamp after which seed reveals close and the draw may run.
+    RevealDeadline(u64),
+    
+    /// Priority request: GROUP_PRIORITY_REQUEST_{id}
+    /// Latest hardship request to be paid in the current cycle of a vote group.
+    PriorityRequest(u64),
+    
+    /// Position holder: GROUP_POSITION_{id}_{position}
+    /// Member holding a payout position, mirroring `MemberProfile::payout_position`.
+    PositionMember(u64, u32),
+    
+    /// Creator groups: GROUP_CREATOR_{address}
+    /// Index of the group IDs created by an address, in ascending order.
+    ByCreator(Address),
 }
 
 /// Storage keys for member-related data.
@@ -70,6 +106,50 @@ pub enum MemberKey {
     /// Member payout eligibility: MEMBER_PAYOUT_{group_id}_{address}
     /// Tracks payout turn order and eligibility status.
     PayoutEligibility(u64, Address),
+    
+    /// Member default marker: MEMBER_DEFAULT_{group_id}_{cycle}_{address}
+    /// Set when the member missed the contribution for the cycle.
+    Defaulted(u64, u32, Address),
+    
+    /// Member default count: MEMBER_DEFAULT_COUNT_{address}
+    /// Total cycles the member has defaulted on, across all groups.
+    DefaultCount(Address),
+    
+    /// Member collateral: MEMBER_COLLATERAL_{group_id}_{address}
+    /// Collateral held in escrow for the member, reduced when slashed.
+    Collateral(u64, Address),
+    
+    /// Member cancel vote: MEMBER_CANCEL_VOTE_{group_id}_{address}
+    /// Set when the member has voted to cancel the group.
+    CancelVote(u64, Address),
+    
+    /// Member refund: MEMBER_REFUND_{group_id}_{address}
+    /// Refund owed to the member after cancellation, removed once claimed.
+    Refund(u64, Address),
+    
+    /// Member seed commitment: MEMBER_SEED_COMMIT_{group_id}_{address}
+    /// sha256 of the seed the member will reveal for the payout draw.
+    SeedCommit(u64, Address),
+    
+    /// Member seed reveal: MEMBER_SEED_REVEAL_{group_id}_{address}
+    /// Seed revealed by the member, checked against the commitment.
+    SeedReveal(u64, Address),
+    
+    /// Member bid: MEMBER_BID_{group_id}_{cycle}_{address}
+    /// Discount the member offers to forgo to win an auction cycle's payout.
+    Bid(u64, u32, Address),
+    
+    /// Member contribution credit: MEMBER_CREDIT_{group_id}_{address}
+    /// Auction dividends applied to the member's next contributions.
+    ContributionCredit(u64, Address),
+    
+    /// Swap proposal: MEMBER_SWAP_{group_id}_{address}
+    /// Member who proposed to exchange payout positions with this member.
+    SwapProposal(u64, Address),
+    
+    /// Member groups: MEMBER_GROUPS_{address}
+    /// Reverse index of the group IDs the address belongs to, in ascending order.
+    Groups(Address),
 }
 
 /// Storage keys for contribution tracking.
@@ -90,6 +170,10 @@ pub enum ContributionKey {
     /// Cycle contributor count: CONTRIB_COUNT_{group_id}_{cycle}
     /// Tracks how many members have contributed in the current cycle.
     CycleCount(u64, u32),
+    
+    /// Cycle default count: CONTRIB_DEFAULTS_{group_id}_{cycle}
+    /// Tracks how many defaulted members in a cycle were not covered by collateral.
+    CycleDefaults(u64, u32),
 }
 
 /// Storage keys for payout records.
@@ -141,6 +225,21 @@ pub enum CounterKey {
 
     /// Global contract configuration.
     ContractConfig,
+
+    /// Contract admin: COUNTER_ADMIN
+    /// Set once by `initialize`, changed only through the two-step transfer.
+    Admin,
+
+    /// Proposed admin awaiting acceptance: COUNTER_PENDING_ADMIN
+    PendingAdmin,
+
+    /// Tokens with recorded stats: COUNTER_STATS_TOKENS
+    /// List of tokens that have a `TokenStats` entry.
+    StatsTokens,
+
+    /// Per-token stats: COUNTER_TOKEN_STATS_{token}
+    /// Value locked and total paid out for one token.
+    TokenStats(Address),
 }
 
 /// Utility functions for creating storage keys with consistent formatting.
@@ -162,9 +261,54 @@ impl StorageKeyBuilder {
         StorageKey::Group(GroupKey::Members(group_id))
     }
     
-    /// Creates a key for storing group status.
-    pub fn group_status(group_id: u64) -> StorageKey {
-        StorageKey::Group(GroupKey::Status(group_id))
+    /// Creates a key for the group's late penalty reserve.
+    pub fn group_reserve(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::Reserve(group_id))
+    }
+    
+    /// Creates a key for the number of cancel votes in a group.
+    pub fn group_cancel_votes(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::CancelVotes(group_id))
+    }
+    
+    /// Creates a key for a cancelled group's refund pool.
+    pub fn group_refund_pool(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::RefundPool(group_id))
+    }
+    
+    /// Creates a key for the record of a group's randomized payout draw.
+    pub fn group_payout_draw(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::PayoutDraw(group_id))
+    }
+    
+    /// Creates a key for the mode a group's payout positions were assigned with.
+    pub fn group_assignment_mode(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::AssignmentMode(group_id))
+    }
+    
+    /// Creates a key for the end of a group's seed commit phase.
+    pub fn group_commit_deadline(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::CommitDeadline(group_id))
+    }
+    
+    /// Creates a key for the end of a group's seed reveal phase.
+    pub fn group_reveal_deadline(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::RevealDeadline(group_id))
+    }
+    
+    /// Creates a key for a group's latest hardship priority request.
+    pub fn group_priority_request(group_id: u64) -> StorageKey {
+        StorageKey::Group(GroupKey::PriorityRequest(group_id))
+    }
+    
+    /// Creates a key for the member holding a payout position in a group.
+    pub fn group_position_member(group_id: u64, position: u32) -> StorageKey {
+        StorageKey::Group(GroupKey::PositionMember(group_id, position))
+    }
+    
+    /// Creates a key for the index of groups created by an address.
+    pub fn creator_groups(creator: Address) -> StorageKey {
+        StorageKey::Group(GroupKey::ByCreator(creator))
     }
     
     // Member key builders
@@ -184,6 +328,61 @@ impl StorageKeyBuilder {
         StorageKey::Member(MemberKey::PayoutEligibility(group_id, address))
     }
     
+    /// Creates a key marking a member as defaulted in a cycle.
+    pub fn member_defaulted(group_id: u64, cycle: u32, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Defaulted(group_id, cycle, address))
+    }
+    
+    /// Creates a key for a member's lifetime default count.
+    pub fn member_default_count(address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::DefaultCount(address))
+    }
+    
+    /// Creates a key for the collateral a member holds in escrow.
+    pub fn member_collateral(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Collateral(group_id, address))
+    }
+    
+    /// Creates a key marking a member's vote to cancel a group.
+    pub fn member_cancel_vote(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::CancelVote(group_id, address))
+    }
+    
+    /// Creates a key for the refund owed to a member of a cancelled group.
+    pub fn member_refund(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Refund(group_id, address))
+    }
+    
+    /// Creates a key for a member's payout draw seed commitment.
+    pub fn member_seed_commit(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::SeedCommit(group_id, address))
+    }
+    
+    /// Creates a key for a member's revealed payout draw seed.
+    pub fn member_seed_reveal(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::SeedReveal(group_id, address))
+    }
+    
+    /// Creates a key for a member's auction bid in a cycle.
+    pub fn member_bid(group_id: u64, cycle: u32, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Bid(group_id, cycle, address))
+    }
+    
+    /// Creates a key for a member's auction dividend credit.
+    pub fn member_contribution_credit(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::ContributionCredit(group_id, address))
+    }
+    
+    /// Creates a key for the swap proposed to a member.
+    pub fn member_swap_proposal(group_id: u64, address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::SwapProposal(group_id, address))
+    }
+    
+    /// Creates a key for the reverse index of groups an address belongs to.
+    pub fn member_groups(address: Address) -> StorageKey {
+        StorageKey::Member(MemberKey::Groups(address))
+    }
+    
     // Contribution key builders
     
     /// Creates a key for individual contribution records.
@@ -201,6 +400,11 @@ impl StorageKeyBuilder {
         StorageKey::Contribution(ContributionKey::CycleCount(group_id, cycle))
     }
     
+    /// Creates a key for the number of defaulted members in a cycle.
+    pub fn contribution_cycle_defaults(group_id: u64, cycle: u32) -> StorageKey {
+        StorageKey::Contribution(ContributionKey::CycleDefaults(group_id, cycle))
+    }
+    
     // Payout key builders
     
     /// Creates a key for payout records.
@@ -249,6 +453,26 @@ impl StorageKeyBuilder {
     pub fn contract_config() -> StorageKey {
         StorageKey::Counter(CounterKey::ContractConfig)
     }
+
+    /// Creates a key for the contract admin.
+    pub fn contract_admin() -> StorageKey {
+        StorageKey::Counter(CounterKey::Admin)
+    }
+
+    /// Creates a key for the proposed admin of a pending transfer.
+    pub fn pending_admin() -> StorageKey {
+        StorageKey::Counter(CounterKey::PendingAdmin)
+    }
+
+    /// Creates a key for the list of tokens with recorded stats.
+    pub fn stats_tokens() -> StorageKey {
+        StorageKey::Counter(CounterKey::StatsTokens)
+    }
+
+    /// Creates a key for a token's value locked and paid out stats.
+    pub fn token_stats(token: Address) -> StorageKey {
+        StorageKey::Counter(CounterKey::TokenStats(token))
+    }
 }
 
 /// Constants for storage key prefixes used in string representations.
@@ -262,8 +486,35 @@ pub mod key_prefixes {
     /// Group members list prefix
     pub const GROUP_MEMBERS: &str = "GROUP_MEMBERS";
     
-    /// Group status prefix
-    pub const GROUP_STATUS: &str = "GROUP_STATUS";
+    /// Group reserve prefix
+    pub const GROUP_RESERVE: &str = "GROUP_RESERVE";
+    
+    /// Group cancel votes prefix
+    pub const GROUP_CANCEL_VOTES: &str = "GROUP_CANCEL_VOTES";
+    
+    /// Group refund pool prefix
+    pub const GROUP_REFUND_POOL: &str = "GROUP_REFUND_POOL";
+    
+    /// Payout draw prefix
+    pub const GROUP_PAYOUT_DRAW: &str = "GROUP_PAYOUT_DRAW";
+    
+    /// Assignment mode prefix
+    pub const GROUP_ASSIGNMENT_MODE: &str = "GROUP_ASSIGNMENT_MODE";
+    
+    /// Commit deadline prefix
+    pub const GROUP_COMMIT_DEADLINE: &str = "GROUP_COMMIT_DEADLINE";
+    
+    /// Reveal deadline prefix
+    pub const GROUP_REVEAL_DEADLINE: &str = "GROUP_REVEAL_DEADLINE";
+    
+    /// Priority request prefix
+    pub const GROUP_PRIORITY_REQUEST: &str = "GROUP_PRIORITY_REQUEST";
+    
+    /// Position holder prefix
+    pub const GROUP_POSITION: &str = "GROUP_POSITION";
+    
+    /// Creator groups index prefix
+    pub const GROUP_CREATOR: &str = "GROUP_CREATOR";
     
     /// Member profile prefix
     pub const MEMBER: &str = "MEMBER";
@@ -274,6 +525,39 @@ pub mod key_prefixes {
     /// Member payout eligibility prefix
     pub const MEMBER_PAYOUT: &str = "MEMBER_PAYOUT";
     
+    /// Member default marker prefix
+    pub const MEMBER_DEFAULT: &str = "MEMBER_DEFAULT";
+    
+    /// Member default count prefix
+    pub const MEMBER_DEFAULT_COUNT: &str = "MEMBER_DEFAULT_COUNT";
+    
+    /// Member collateral prefix
+    pub const MEMBER_COLLATERAL: &str = "MEMBER_COLLATERAL";
+    
+    /// Member cancel vote prefix
+    pub const MEMBER_CANCEL_VOTE: &str = "MEMBER_CANCEL_VOTE";
+    
+    /// Member refund prefix
+    pub const MEMBER_REFUND: &str = "MEMBER_REFUND";
+    
+    /// Member seed commitment prefix
+    pub const MEMBER_SEED_COMMIT: &str = "MEMBER_SEED_COMMIT";
+    
+    /// Member seed reveal prefix
+    pub const MEMBER_SEED_REVEAL: &str = "MEMBER_SEED_REVEAL";
+    
+    /// Member bid prefix
+    pub const MEMBER_BID: &str = "MEMBER_BID";
+    
+    /// Member contribution credit prefix
+    pub const MEMBER_CREDIT: &str = "MEMBER_CREDIT";
+    
+    /// Member swap proposal prefix
+    pub const MEMBER_SWAP: &str = "MEMBER_SWAP";
+    
+    /// Member groups index prefix
+    pub const MEMBER_GROUPS: &str = "MEMBER_GROUPS";
+    
     /// Individual contribution prefix
     pub const CONTRIB: &str = "CONTRIB";
     
@@ -283,6 +567,9 @@ pub mod key_prefixes {
     /// Cycle contributor count prefix
     pub const CONTRIB_COUNT: &str = "CONTRIB_COUNT";
     
+    /// Cycle default count prefix
+    pub const CONTRIB_DEFAULTS: &str = "CONTRIB_DEFAULTS";
+    
     /// Payout record prefix
     pub const PAYOUT: &str = "PAYOUT";
     
@@ -316,12 +603,15 @@ mod tests {
         
         let data_key = StorageKeyBuilder::group_data(group_id);
         let members_key = StorageKeyBuilder::group_members(group_id);
-        let status_key = StorageKeyBuilder::group_status(group_id);
+        let reserve_key = StorageKeyBuilder::group_reserve(group_id);
         
         // Verify the keys are different
         assert_ne!(data_key, members_key);
-        assert_ne!(data_key, status_key);
-        assert_ne!(members_key, status_key);
+        assert_ne!(members_key, reserve_key);
+        assert_ne!(
+            StorageKeyBuilder::group_cancel_votes(group_id),
+            StorageKeyBuilder::group_refund_pool(group_id)
+        );
         
         // Verify they contain the correct group ID
         match data_key {
@@ -339,11 +629,36 @@ mod tests {
         let profile_key = StorageKeyBuilder::member_profile(group_id, address.clone());
         let contrib_key = StorageKeyBuilder::member_contribution_status(group_id, address.clone());
         let payout_key = StorageKeyBuilder::member_payout_eligibility(group_id, address.clone());
+        let defaulted_key = StorageKeyBuilder::member_defaulted(group_id, 0, address.clone());
+        let default_count_key = StorageKeyBuilder::member_default_count(address.clone());
         
         // Verify all keys are different
         assert_ne!(profile_key, contrib_key);
         assert_ne!(profile_key, payout_key);
         assert_ne!(contrib_key, payout_key);
+        assert_ne!(defaulted_key, default_count_key);
+        assert_ne!(profile_key, defaulted_key);
+        assert_ne!(
+            profile_key,
+            StorageKeyBuilder::member_collateral(group_id, address.clone())
+        );
+        assert_ne!(
+            StorageKeyBuilder::member_cancel_vote(group_id, address.clone()),
+            StorageKeyBuilder::member_refund(group_id, address.clone())
+        );
+        assert_ne!(default_count_key, StorageKeyBuilder::member_groups(address.clone()));
+        assert_ne!(
+            StorageKeyBuilder::member_seed_commit(group_id, address.clone()),
+            StorageKeyBuilder::member_seed_reveal(group_id, address.clone())
+        );
+        assert_ne!(
+            StorageKeyBuilder::member_bid(group_id, 0, address.clone()),
+            StorageKeyBuilder::member_contribution_credit(group_id, address.clone())
+        );
+        assert_ne!(
+            StorageKeyBuilder::member_swap_proposal(group_id, address.clone()),
+            StorageKeyBuilder::member_profile(group_id, address.clone())
+        );
         
         // Verify they contain the correct data
         match profile_key {
@@ -365,11 +680,13 @@ mod tests {
         let individual_key = StorageKeyBuilder::contribution_individual(group_id, cycle, address.clone());
         let total_key = StorageKeyBuilder::contribution_cycle_total(group_id, cycle);
         let count_key = StorageKeyBuilder::contribution_cycle_count(group_id, cycle);
+        let defaults_key = StorageKeyBuilder::contribution_cycle_defaults(group_id, cycle);
         
         // Verify all keys are different
         assert_ne!(individual_key, total_key);
         assert_ne!(individual_key, count_key);
         assert_ne!(total_key, count_key);
+        assert_ne!(count_key, defaults_key);
         
         // Verify they contain the correct data
         match individual_key {
@@ -413,11 +730,15 @@ mod tests {
         let active_groups_key = StorageKeyBuilder::active_groups();
         let total_members_key = StorageKeyBuilder::total_members();
         let version_key = StorageKeyBuilder::contract_version();
+        let config_key = StorageKeyBuilder::contract_config();
+        let admin_key = StorageKeyBuilder::contract_admin();
+        let pending_admin_key = StorageKeyBuilder::pending_admin();
         
         // Verify all keys are different
         let keys = [
             &next_id_key, &total_groups_key, &active_groups_key, 
-            &total_members_key, &version_key
+            &total_members_key, &version_key, &config_key,
+            &admin_key, &pending_admin_key
         ];
         
         for i in 0..keys.len() {
@@ -0,0 +1,213 @@
+use soroban_sdk::{Address, Env, Vec};
+use crate::{
+    group::Group,
+    status::GroupStatus,
+    storage::{StorageKey, StorageKeyBuilder},
+};
+
+/// Average ledger close time used to convert seconds into ledgers.
+pub const LEDGER_SECONDS: u64 = 5;
+
+/// How long a group's data is kept live after its rotation ends, so members
+/// can still claim refunds and collateral and read the history (30 days).
+pub const RETENTION_SECONDS: u64 = 30 * 24 * 60 * 60;
+
+/// How long a platform-wide key may age before it is extended back to the
+/// maximum TTL, so that not every call pays to extend it (7 days).
+pub const GLOBAL_REFRESH_SECONDS: u64 = 7 * 24 * 60 * 60;
+
+/// Returns the TTL, in ledgers, that covers the rest of the group's lifetime.
+///
+/// A running or pending group needs its remaining cycles (each with its grace
+/// period) plus the retention window; a finished group only the retention
+/// window. The result is capped at the network's maximum TTL.
+pub fn group_lifetime_ledgers(env: &Env, group: &Group) -> u32 {
+    let remaining_seconds = match group.status {
+        GroupStatus::Completed | GroupStatus::Cancelled => 0,
+        _ => {
+            let remaining_cycles = group.max_members.saturating_sub(group.current_cycle) as u64;
+            remaining_cycles
+                .saturating_mul(group.cycle_duration.saturating_add(group.grace_period))
+        }
+    };
+    let ledgers = remaining_seconds.saturating_add(RETENTION_SECONDS) / LEDGER_SECONDS;
+    let max_ttl = env.storage().max_ttl();
+    if ledgers > max_ttl as u64 {
+        max_ttl
+    } else {
+        ledgers as u32
+    }
+}
+
+/// Extends a persistent key to `ledgers` if it exists and currently expires sooner.
+fn extend_key(env: &Env, key: &StorageKey, ledgers: u32) {
+    let storage = env.storage().persistent();
+    if storage.has(key) {
+        storage.extend_ttl(key, ledgers, ledgers);
+    }
+}
+
+/// Extends a platform-wide key (counters, stats) to the maximum TTL once it
+/// has aged by `GLOBAL_REFRESH_SECONDS`.
+pub fn extend_global(env: &Env, key: &StorageKey) {
+    let max_ttl = env.storage().max_ttl();
+    let threshold = max_ttl.saturating_sub((GLOBAL_REFRESH_SECONDS / LEDGER_SECONDS) as u32);
+    let storage = env.storage().persistent();
+    if storage.has(key) {
+        storage.extend_ttl(key, threshold, max_ttl);
+    }
+}
+
+/// Extends the admin, configuration and schema version keys to the maximum TTL.
+pub fn extend_contract(env: &Env) {
+    extend_global(env, &StorageKeyBuilder::contract_admin());
+    extend_global(env, &StorageKeyBuilder::contract_config());
+    extend_global(env, &StorageKeyBuilder::contract_version());
+}
+
+/// Extends the contract instance and the group-level keys of `group`.
+pub fn extend_group(env: &Env, group: &Group) {
+    let ledgers = group_lifetime_ledgers(env, group);
+    let group_id = group.id;
+
+    env.storage().instance().extend_ttl(ledgers, ledgers);
+    extend_key(env, &StorageKeyBuilder::group_data(group_id), ledgers);
+    extend_key(env, &StorageKeyBuilder::group_members(group_id), ledgers);
+    extend_key(env, &StorageKeyBuilder::group_reserve(group_id), ledgers);
+    extend_key(env, &StorageKeyBuilder::group_cancel_votes(group_id), ledgers);
+    extend_key(env, &StorageKeyBuilder::group_refund_pool(group_id), ledgers);
+    extend_key(env, &StorageKeyBuilder::group_payout_draw(group_id), ledgers);
+    extend_key(env, &StorageKeyBuilder::group_assignment_mode(group_id), ledgers);
+    extend_key(env, &StorageKeyBuilder::group_commit_deadline(group_id), ledgers);
+    extend_key(env, &StorageKeyBuilder::group_reveal_deadline(group_id), ledgers);
+    extend_key(env, &StorageKeyBuilder::group_priority_request(group_id), ledgers);
+    extend_key(env, &StorageKeyBuilder::creator_groups(group.creator.clone()), ledgers);
+    extend_cycle(env, group, group.current_cycle);
+}
+
+/// Extends the contribution and payout keys of one cycle of `group`, and
+/// the index entry of the member paid in it.
+pub fn extend_cycle(env: &Env, group: &Group, cycle: u32) {
+    let ledgers = group_lifetime_ledgers(env, group);
+    let group_id = group.id;
+
+    extend_key(env, &StorageKeyBuilder::contribution_cycle_total(group_id, cycle), ledgers);
+    extend_key(env, &StorageKeyBuilder::contribution_cycle_count(group_id, cycle), ledgers);
+    extend_key(env, &StorageKeyBuilder::contribution_cycle_defaults(group_id, cycle), ledgers);
+    extend_key(env, &StorageKeyBuilder::payout_record(group_id, cycle), ledgers);
+    extend_key(env, &StorageKeyBuilder::payout_recipient(group_id, cycle), ledgers);
+    extend_key(env, &StorageKeyBuilder::payout_status(group_id, cycle), ledgers);
+    extend_key(env, &StorageKeyBuilder::group_position_member(group_id, cycle), ledgers);
+}
+
+/// Extends the index entry of the member holding `position` in `group`.
+pub fn extend_position(env: &Env, group: &Group, position: u32) {
+    let ledgers = group_lifetime_ledgers(env, group);
+    extend_key(env, &StorageKeyBuilder::group_position_member(group.id, position), ledgers);
+}
+
+/// Extends the keys belonging to `member` in `group`, including their
+/// contribution and default markers for the current cycle.
+pub fn extend_member(env: &Env, group: &Group, member: &Address) {
+    let ledgers = group_lifetime_ledgers(env, group);
+    let group_id = group.id;
+
+    extend_key(env, &StorageKeyBuilder::member_profile(group_id, member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_contribution_status(group_id, member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_payout_eligibility(group_id, member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_collateral(group_id, member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_cancel_vote(group_id, member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_refund(group_id, member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_default_count(member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_groups(member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_seed_commit(group_id, member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_seed_reveal(group_id, member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_contribution_credit(group_id, member.clone()), ledgers);
+    extend_key(env, &StorageKeyBuilder::member_swap_proposal(group_id, member.clone()), ledgers);
+    extend_member_cycle(env, group, member, group.current_cycle, ledgers);
+}
+
+/// Extends the per-cycle keys of `member` in one cycle.
+fn extend_member_cycle(env: &Env, group: &Group, member: &Address, cycle: u32, ledgers: u32) {
+    extend_key(
+        env,
+        &StorageKeyBuilder::contribution_individual(group.id, cycle, member.clone()),
+        ledgers,
+    );
+    extend_key(
+        env,
+        &StorageKeyBuilder::member_defaulted(group.id, cycle, member.clone()),
+        ledgers,
+    );
+    extend_key(
+        env,
+        &StorageKeyBuilder::member_bid(group.id, cycle, member.clone()),
+        ledgers,
+    );
+}
+
+/// Extends every key belonging to `group`: group-level keys, every member's
+/// keys and the contribution and payout keys of every cycle so far.
+pub fn extend_all(env: &Env, group: &Group) {
+    let ledgers = group_lifetime_ledgers(env, group);
+    let members: Vec<Address> = env.storage()
+        .persistent()
+        .get(&StorageKeyBuilder::group_members(group.id))
+        .unwrap_or(Vec::new(env));
+
+    extend_group(env, group);
+    for cycle in 0..=group.current_cycle {
+        extend_cycle(env, group, cycle);
+    }
+    for position in group.current_cycle..group.member_count {
+        extend_position(env, group, position);
+    }
+    for member in members.iter() {
+        extend_member(env, group, &member);
+        for cycle in 0..group.current_cycle {
+            extend_member_cycle(env, group, &member, cycle, ledgers);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_lifetime_covers_remaining_cycles() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let mut group = Group::new(1, creator, Address::generate(&env), 100, 86_400, 5, 0);
+
+        let pending = group_lifetime_ledgers(&env, &group);
+        let expected = (5 * 86_400 + RETENTION_SECONDS) / LEDGER_SECONDS;
+        assert_eq!(pending as u64, expected.min(env.storage().max_ttl() as u64));
+
+        group.current_cycle = 4;
+        assert!(group_lifetime_ledgers(&env, &group) < pending);
+    }
+
+    #[test]
+    fn test_finished_group_keeps_retention_only() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let mut group = Group::new(1, creator, Address::generate(&env), 100, 86_400, 5, 0);
+        group.status = GroupStatus::Cancelled;
+
+        assert_eq!(
+            group_lifetime_ledgers(&env, &group) as u64,
+            RETENTION_SECONDS / LEDGER_SECONDS
+        );
+    }
+
+    #[test]
+    fn test_lifetime_capped_at_max_ttl() {
+        let env = Env::default();
+        let creator = Address::generate(&env);
+        let group = Group::new(1, creator, Address::generate(&env), 100, 31_536_000, 100, 0);
+
+        assert_eq!(group_lifetime_ledgers(&env, &group), env.storage().max_ttl());
+    }
+}